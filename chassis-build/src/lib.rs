@@ -0,0 +1,153 @@
+#![doc(html_root_url = "https://docs.rs/chassis-build/0.2.0")]
+
+//! Build script API of [chassis](https://docs.rs/chassis).
+//!
+//! Instead of annotating an integration module with `#[chassis::integration]`, the modules and
+//! components can be described in a TOML file, which is compiled to Rust code by a build
+//! script. The same checks as for the attribute are done and the generated code is written to
+//! `OUT_DIR`, where it can be inspected.
+//!
+//! ## Use
+//!
+//! Add `chassis-build` to your build dependencies in your `Cargo.toml`:
+//! ```toml
+//! [build-dependencies]
+//! chassis-build = "^0.2.0"
+//! ```
+//!
+//! Compile the spec in your `build.rs`:
+//! ```rust,ignore
+//! fn main() {
+//!     chassis_build::compile("src/di.toml");
+//! }
+//! ```
+//!
+//! Include the generated code, which is named after the spec file, where the components
+//! should be defined:
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/di.rs"));
+//! ```
+//!
+//! ## Spec
+//!
+//! Modules are the Rust types whose associated functions provide the dependencies. Every
+//! provider function is declared with its argument and return types. Components are the
+//! generated traits and their implementations. All types and modules are Rust paths which
+//! are resolved at the place where the generated code is included.
+//! ```toml
+//! [[module]]
+//! path = "crate::greeting::GreetingModule"
+//!
+//! [[module.provider]]
+//! name = "provide_message"
+//! returns = "crate::greeting::Message"
+//!
+//! [[module.provider]]
+//! name = "provide_greeter"
+//! args = ["crate::greeting::Message"]
+//! returns = "crate::greeting::Greeter"
+//! singleton = true
+//!
+//! [[component]]
+//! name = "GreetingComponent"
+//!
+//! [[component.request]]
+//! name = "greeter"
+//! returns = "crate::greeting::Greeter"
+//! ```
+//! Providers can be marked with `singleton`, `reusable` and `binds` like with the attributes.
+//! A module can include other modules with `includes = [..]` and a component can select its
+//! modules with `modules = [..]` and list types to create with `Default::default()` with
+//! `default_bindings = [..]`.
+//!
+//! Like for the attribute, dependencies are looked up by their syntax, so a type has to be
+//! spelled the same way everywhere in the spec.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chassis_codegen::ChassisError;
+use quote::quote;
+
+use crate::spec::Spec;
+
+mod spec;
+
+/// Error while compiling a spec.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// invalid spec file
+    Spec(toml::de::Error),
+    /// invalid Rust syntax in the spec
+    Syntax(String, syn::Error),
+    /// invalid dependency graph
+    Chassis(ChassisError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Spec(err) => write!(f, "Invalid spec: {}", err),
+            Error::Syntax(input, err) => write!(f, "Invalid Rust syntax `{}`: {}", input, err),
+            Error::Chassis(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+/// Compile the spec at `path` to `$OUT_DIR/<file stem>.rs`.
+///
+/// Meant to be called from a build script, so it panics on errors.
+pub fn compile(path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    println!("cargo:rerun-if-changed={}", path.display());
+    if let Err(err) = try_compile(path) {
+        panic!("Failed to compile `{}`: {}", path.display(), err);
+    }
+}
+
+/// Compile the spec at `path` to `$OUT_DIR/<file stem>.rs` and return the path of the
+/// generated file.
+pub fn try_compile(path: impl AsRef<Path>) -> Result<PathBuf, Error> {
+    let path = path.as_ref();
+    let code = generate(&fs::read_to_string(path)?)?;
+
+    let out_dir = std::env::var_os("OUT_DIR")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "OUT_DIR is not set"))?;
+    let file_name = path
+        .file_stem()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Expected spec file"))?;
+    let out_path = Path::new(&out_dir).join(file_name).with_extension("rs");
+    fs::write(&out_path, code)?;
+    Ok(out_path)
+}
+
+/// Generate the Rust code for the spec `source`.
+pub fn generate(source: &str) -> Result<String, Error> {
+    let spec: Spec = toml::from_str(source).map_err(Error::Spec)?;
+
+    let mut items = spec.to_items()?;
+    let component_impls = chassis_codegen::generate(&mut items).map_err(Error::Chassis)?;
+
+    // modules are only declarations of existing code
+    let traits = items
+        .iter()
+        .filter(|item| matches!(item, syn::Item::Trait(_)));
+    let file: syn::File = syn::parse2(quote! {
+        #(#traits)*
+        #component_impls
+    })
+    .map_err(|err| Error::Chassis(ChassisError::InternalError(err.to_string())))?;
+    Ok(prettyplease::unparse(&file))
+}
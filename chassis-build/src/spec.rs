@@ -0,0 +1,166 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use serde::Deserialize;
+
+use crate::Error;
+
+/// Declarative description of modules and components.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Spec {
+    #[serde(default, rename = "module")]
+    modules: Vec<ModuleSpec>,
+    #[serde(default, rename = "component")]
+    components: Vec<ComponentSpec>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ModuleSpec {
+    /// path to the module type
+    path: String,
+    #[serde(default)]
+    includes: Vec<String>,
+    #[serde(default, rename = "provider")]
+    providers: Vec<ProviderSpec>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ProviderSpec {
+    /// name of the function in the module
+    name: String,
+    #[serde(default)]
+    args: Vec<String>,
+    returns: String,
+    #[serde(default)]
+    singleton: bool,
+    #[serde(default)]
+    reusable: bool,
+    #[serde(default)]
+    binds: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ComponentSpec {
+    /// name of the generated trait
+    name: String,
+    modules: Option<Vec<String>>,
+    #[serde(default)]
+    default_bindings: Vec<String>,
+    #[serde(default, rename = "request")]
+    requests: Vec<RequestSpec>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct RequestSpec {
+    name: String,
+    returns: String,
+}
+
+impl Spec {
+    /// Rust items with the same meaning as the spec inside of an `#[integration]` module.
+    ///
+    /// Provider functions only declare the signature of the real functions.
+    pub fn to_items(&self) -> Result<Vec<syn::Item>, Error> {
+        let mut items = vec![];
+        for module in &self.modules {
+            items.push(syn::Item::Impl(module.to_item()?));
+        }
+        for component in &self.components {
+            items.push(syn::Item::Trait(component.to_item()?));
+        }
+        Ok(items)
+    }
+}
+
+impl ModuleSpec {
+    fn to_item(&self) -> Result<syn::ItemImpl, Error> {
+        let path: syn::Path = parse(&self.path)?;
+        let includes = parse_list::<syn::Path>(&self.includes)?;
+        let providers = self
+            .providers
+            .iter()
+            .map(ProviderSpec::to_tokens)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(syn::parse_quote! {
+            #[module(includes = [#(#includes),*])]
+            impl #path {
+                #(#providers)*
+            }
+        })
+    }
+}
+
+impl ProviderSpec {
+    fn to_tokens(&self) -> Result<TokenStream2, Error> {
+        let name: syn::Ident = parse(&self.name)?;
+        let returns: syn::Type = parse(&self.returns)?;
+        let args = parse_list::<syn::Type>(&self.args)?;
+        let arg_names = (0..args.len()).map(|i| format_ident!("arg{}", i));
+
+        let singleton = if self.singleton {
+            quote! { #[singleton] }
+        } else {
+            TokenStream2::new()
+        };
+        let reusable = if self.reusable {
+            quote! { #[reusable] }
+        } else {
+            TokenStream2::new()
+        };
+        let (binds, body) = if self.binds {
+            (quote! { #[binds] }, quote! { ; })
+        } else {
+            (TokenStream2::new(), quote! { { unreachable!() } })
+        };
+
+        Ok(quote! {
+            #singleton
+            #reusable
+            #binds
+            pub fn #name(#(#arg_names: #args),*) -> #returns #body
+        })
+    }
+}
+
+impl ComponentSpec {
+    fn to_item(&self) -> Result<syn::ItemTrait, Error> {
+        let name: syn::Ident = parse(&self.name)?;
+        let modules = match &self.modules {
+            Some(modules) => {
+                let modules = parse_list::<syn::Path>(modules)?;
+                quote! { modules = [#(#modules),*], }
+            }
+            None => TokenStream2::new(),
+        };
+        let default_bindings = parse_list::<syn::Path>(&self.default_bindings)?;
+        let requests = self
+            .requests
+            .iter()
+            .map(|request| {
+                let name: syn::Ident = parse(&request.name)?;
+                let returns: syn::Type = parse(&request.returns)?;
+                Ok(quote! { fn #name(&self) -> #returns; })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(syn::parse_quote! {
+            #[component(#modules default_bindings = [#(#default_bindings),*])]
+            pub trait #name {
+                #(#requests)*
+            }
+        })
+    }
+}
+
+fn parse<T: syn::parse::Parse>(input: &str) -> Result<T, Error> {
+    syn::parse_str(input).map_err(|err| Error::Syntax(input.to_string(), err))
+}
+
+fn parse_list<T: syn::parse::Parse>(inputs: &[String]) -> Result<Vec<T>, Error> {
+    inputs.iter().map(|input| parse(input)).collect()
+}
@@ -0,0 +1,81 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chassis_build::{compile, try_compile};
+
+const MAIN: &str = r#"
+mod greeting {
+    #[derive(Clone)]
+    pub struct Message(pub &'static str);
+
+    #[derive(Clone)]
+    pub struct Greeter(pub Message);
+
+    pub struct GreetingModule;
+
+    impl GreetingModule {
+        pub fn provide_message() -> Message {
+            Message("Hello")
+        }
+
+        pub fn provide_greeter(message: Message) -> Greeter {
+            Greeter(message)
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/greeting.rs"));
+
+fn main() {
+    let component = GreetingComponentImpl::new();
+    print!("{}", component.greeter().0 .0);
+}
+"#;
+
+fn spec_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/specs")
+        .join(name)
+}
+
+/// Point `OUT_DIR` to a fresh directory like cargo does for build scripts.
+fn out_dir() -> PathBuf {
+    let out_dir = env::temp_dir().join(format!("chassis-build-test-{}", std::process::id()));
+    fs::create_dir_all(&out_dir).unwrap();
+    env::set_var("OUT_DIR", &out_dir);
+    out_dir
+}
+
+#[test]
+fn check_try_compile() {
+    let out_dir = out_dir();
+
+    let out_path = try_compile(spec_path("greeting.toml")).unwrap();
+    assert_eq!(out_dir.join("greeting.rs"), out_path);
+
+    // the generated code has to compile together with the modules of the spec
+    let main_path = out_dir.join("main.rs");
+    fs::write(&main_path, MAIN).unwrap();
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let status = Command::new(rustc)
+        .args(["--edition", "2018", "--crate-name", "greeting", "-o"])
+        .arg(out_dir.join("greeting"))
+        .arg(&main_path)
+        .env("OUT_DIR", &out_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let output = Command::new(out_dir.join("greeting")).output().unwrap();
+    assert_eq!("Hello", String::from_utf8(output.stdout).unwrap());
+
+    fs::remove_dir_all(&out_dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Failed to compile")]
+fn check_compile_invalid_spec() {
+    compile(spec_path("invalid.toml"));
+}
@@ -0,0 +1,83 @@
+use chassis_build::{generate, Error};
+
+const GREETING_SPEC: &str = r#"
+[[module]]
+path = "crate::greeting::GreetingModule"
+
+[[module.provider]]
+name = "provide_message"
+returns = "crate::greeting::Message"
+
+[[module.provider]]
+name = "provide_greeter"
+args = ["crate::greeting::Message"]
+returns = "crate::greeting::Greeter"
+singleton = true
+
+[[component]]
+name = "GreetingComponent"
+
+[[component.request]]
+name = "greeter"
+returns = "crate::greeting::Greeter"
+"#;
+
+#[test]
+fn check_generate() {
+    let code = generate(GREETING_SPEC).unwrap();
+
+    assert!(code.contains("pub trait GreetingComponent {"));
+    assert!(code.contains("impl GreetingComponent for GreetingComponentImpl {"));
+    assert!(code.contains("crate::greeting::GreetingModule::provide_greeter("));
+    assert!(code.contains("crate::greeting::GreetingModule::provide_message()"));
+    // provider declarations are not generated
+    assert!(!code.contains("unreachable!"));
+}
+
+#[test]
+fn check_default_bindings() {
+    let spec = r#"
+        [[component]]
+        name = "ConfigComponent"
+        default_bindings = ["crate::Config"]
+
+        [[component.request]]
+        name = "config"
+        returns = "crate::Config"
+    "#;
+    let code = generate(spec).unwrap();
+
+    assert!(code.contains("<crate::Config as ::std::default::Default>::default()"));
+}
+
+#[test]
+fn check_missing_dependency() {
+    let spec = GREETING_SPEC.replace(
+        "returns = \"crate::greeting::Message\"",
+        "returns = \"Message\"",
+    );
+    match generate(&spec) {
+        Err(err @ Error::Chassis(_)) => assert!(err
+            .to_string()
+            .starts_with("Missing dependency `crate::greeting::Message`")),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn check_invalid_syntax() {
+    let spec = GREETING_SPEC.replace("crate::greeting::GreetingModule", "crate::");
+    match generate(&spec) {
+        Err(Error::Syntax(input, _)) => assert_eq!("crate::", input),
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn check_unknown_field() {
+    let spec = GREETING_SPEC.replace("singleton = true", "singelton = true");
+    match generate(&spec) {
+        Err(Error::Spec(_)) => {}
+        res => panic!("Unexpected result: {:?}", res),
+    }
+}
@@ -0,0 +1,175 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::{Meta, NestedMeta};
+
+use crate::errors::{ChassisError, ChassisResult};
+use crate::parse::eq_attr_name;
+use crate::utils::to_tokens;
+
+/// Maximal count of distinct cfg predicates in one integration module.
+///
+/// For every combination of predicates a dependency graph is generated.
+pub const MAX_CFG_PREDICATES: usize = 8;
+
+/// Condition of a `#[cfg(...)]` attribute
+#[derive(Clone)]
+pub enum CfgExpr {
+    /// predicate like `feature = "x"` or `test`
+    Predicate(NestedMeta),
+    All(Vec<CfgExpr>),
+    Any(Vec<CfgExpr>),
+    Not(Box<CfgExpr>),
+}
+
+/// Assignment of truth values to cfg predicates.
+pub struct CfgCombination<'a> {
+    predicates: &'a [String],
+    values: u32,
+}
+
+impl CfgExpr {
+    /// Parse all `#[cfg(...)]` attributes in `attrs`.
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> ChassisResult<Option<CfgExpr>> {
+        let exprs = attrs
+            .iter()
+            .filter(|attr| eq_attr_name(attr, "cfg"))
+            .map(|attr| match attr.parse_meta() {
+                Ok(Meta::List(list)) if list.nested.len() == 1 => {
+                    Self::from_nested_meta(&list.nested[0])
+                }
+                _ => Err(ChassisError::IllegalInput(
+                    "Expected `#[cfg(...)]` with one predicate".to_string(),
+                    attr.span(),
+                )),
+            })
+            .collect::<ChassisResult<Vec<CfgExpr>>>()?;
+        Ok(Self::all(exprs))
+    }
+
+    fn from_nested_meta(meta: &NestedMeta) -> ChassisResult<CfgExpr> {
+        match meta {
+            NestedMeta::Meta(Meta::List(list)) => {
+                let args = list
+                    .nested
+                    .iter()
+                    .map(Self::from_nested_meta)
+                    .collect::<ChassisResult<Vec<CfgExpr>>>()?;
+                if list.path.is_ident("all") {
+                    Ok(CfgExpr::All(args))
+                } else if list.path.is_ident("any") {
+                    Ok(CfgExpr::Any(args))
+                } else if list.path.is_ident("not") && args.len() == 1 {
+                    Ok(CfgExpr::Not(Box::new(args.into_iter().next().unwrap())))
+                } else {
+                    Err(ChassisError::IllegalInput(
+                        format!("Unsupported cfg predicate `{}`", to_tokens(list)),
+                        list.span(),
+                    ))
+                }
+            }
+            _ => Ok(CfgExpr::Predicate(meta.clone())),
+        }
+    }
+
+    /// Combine optional conditions which all have to be true.
+    pub fn all(exprs: impl IntoIterator<Item = CfgExpr>) -> Option<CfgExpr> {
+        let mut exprs: Vec<CfgExpr> = exprs.into_iter().collect();
+        match exprs.len() {
+            0 => None,
+            1 => exprs.pop(),
+            _ => Some(CfgExpr::All(exprs)),
+        }
+    }
+
+    /// Add all distinct predicates to `predicates`.
+    pub fn collect_predicates(&self, predicates: &mut Vec<String>) {
+        match self {
+            CfgExpr::Predicate(meta) => {
+                let predicate = to_tokens(meta).to_string();
+                if !predicates.contains(&predicate) {
+                    predicates.push(predicate);
+                }
+            }
+            CfgExpr::All(exprs) | CfgExpr::Any(exprs) => exprs
+                .iter()
+                .for_each(|expr| expr.collect_predicates(predicates)),
+            CfgExpr::Not(expr) => expr.collect_predicates(predicates),
+        }
+    }
+
+    /// Evaluate condition for `combination`.
+    pub fn eval(&self, combination: &CfgCombination) -> bool {
+        match self {
+            CfgExpr::Predicate(meta) => combination.value(&to_tokens(meta).to_string()),
+            CfgExpr::All(exprs) => exprs.iter().all(|expr| expr.eval(combination)),
+            CfgExpr::Any(exprs) => exprs.iter().any(|expr| expr.eval(combination)),
+            CfgExpr::Not(expr) => !expr.eval(combination),
+        }
+    }
+}
+
+impl ToTokens for CfgExpr {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        tokens.extend(match self {
+            CfgExpr::Predicate(meta) => quote! { #meta },
+            CfgExpr::All(exprs) => quote! { all(#(#exprs),*) },
+            CfgExpr::Any(exprs) => quote! { any(#(#exprs),*) },
+            CfgExpr::Not(expr) => quote! { not(#expr) },
+        })
+    }
+}
+
+impl<'a> CfgCombination<'a> {
+    /// All combinations of truth values for `predicates`.
+    pub fn all(predicates: &'a [String]) -> impl Iterator<Item = CfgCombination<'a>> {
+        (0..1u32 << predicates.len()).map(move |values| CfgCombination { predicates, values })
+    }
+
+    /// Whether an item with the optional condition `cfg` is compiled in this combination.
+    pub fn enables(&self, cfg: Option<&CfgExpr>) -> bool {
+        match cfg {
+            Some(cfg) => cfg.eval(self),
+            None => true,
+        }
+    }
+
+    fn value(&self, predicate: &str) -> bool {
+        let index = self
+            .predicates
+            .iter()
+            .position(|p| p == predicate)
+            .expect("unknown cfg predicate");
+        self.values & (1 << index) != 0
+    }
+
+    /// Prepend a cfg attribute for this combination to every item in `items`.
+    pub fn gate_items(&self, items: TokenStream2) -> ChassisResult<TokenStream2> {
+        if self.predicates.is_empty() {
+            return Ok(items);
+        }
+
+        let conditions: Vec<TokenStream2> = self
+            .predicates
+            .iter()
+            .enumerate()
+            .map(|(i, predicate)| {
+                let predicate: TokenStream2 = predicate.parse().unwrap();
+                if self.values & (1 << i) != 0 {
+                    predicate
+                } else {
+                    quote! { not(#predicate) }
+                }
+            })
+            .collect();
+        let file: syn::File =
+            syn::parse2(items).map_err(|err| ChassisError::InternalError(err.to_string()))?;
+        let items = file.items.iter().map(|item| {
+            quote! {
+                #[cfg(all(#(#conditions),*))]
+                #item
+            }
+        });
+        Ok(quote! { #(#items)* })
+    }
+}
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::ops::Deref;
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 
 use crate::container::IocContainer;
 use crate::errors::{ChassisError, ChassisResult};
@@ -22,6 +22,9 @@ pub struct CodegenContext<'a> {
     container: &'a IocContainer,
     resolving: RefCell<Vec<(StaticKey, Span)>>,
     env: CodegenEnv,
+
+    /// statements to run before the generated expression, in dependency order
+    hoisted: RefCell<Vec<(Ident, TokenStream2)>>,
 }
 
 pub struct CodegenContextScope<'a, 'b> {
@@ -35,9 +38,27 @@ impl<'a> CodegenContext<'a> {
             container,
             env,
             resolving: RefCell::new(vec![]),
+            hoisted: RefCell::new(vec![]),
         }
     }
 
+    /// Add statement defining variable `var` before the generated expression.
+    pub fn hoist(&self, var: Ident, statement: TokenStream2) {
+        self.hoisted.borrow_mut().push((var, statement));
+    }
+
+    pub fn is_hoisted(&self, var: &Ident) -> bool {
+        self.hoisted.borrow().iter().any(|(v, _)| v == var)
+    }
+
+    pub fn hoisted_statements(&self) -> Vec<TokenStream2> {
+        self.hoisted
+            .borrow()
+            .iter()
+            .map(|(_, statement)| statement.clone())
+            .collect()
+    }
+
     pub fn env(&self) -> CodegenEnv {
         self.env
     }
@@ -46,6 +67,23 @@ impl<'a> CodegenContext<'a> {
         Self::dependency_chain_inner(&self.resolving.borrow())
     }
 
+    /// Expression for the fallback injector if the component has one.
+    pub fn fallback(&self) -> Option<TokenStream2> {
+        if !self.container.has_fallback() {
+            return None;
+        }
+
+        Some(match self.env {
+            CodegenEnv::Ctor => quote! { fallback },
+            CodegenEnv::TraitImpl => quote! { self.fallback },
+        })
+    }
+
+    /// Error for missing `key` in the current dependency chain.
+    pub fn missing_dependency(&self, key: &StaticKey) -> ChassisError {
+        ChassisError::MissingDependency(self.dependency_chain(), self.container.near_matches(key))
+    }
+
     fn dependency_chain_inner(resolving: &[(StaticKey, Span)]) -> Vec<(String, Span)> {
         resolving
             .iter()
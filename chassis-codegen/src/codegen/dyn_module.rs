@@ -0,0 +1,157 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+
+use crate::cfg::CfgExpr;
+use crate::errors::{ChassisError, ChassisResult};
+use crate::key::StaticKey;
+use crate::model::{Binding, BindingType, Block, Module};
+use crate::parse::{parse_inject_type, WrapperType};
+
+/// Implements `dyn_chassis::Module` for every module of the integration `block`.
+///
+/// Modules consisting of multiple impl blocks are registered in one implementation.
+pub fn codegen_dyn_modules(block: &Block) -> ChassisResult<TokenStream2> {
+    let mut groups: Vec<(StaticKey, Vec<&Module>)> = vec![];
+    for module in &block.modules {
+        let key = StaticKey::try_new(&syn::Type::Path(module.name.clone()))?;
+        match groups.iter_mut().find(|(other, _)| other == &key) {
+            Some((_, modules)) => modules.push(module),
+            None => groups.push((key, vec![module])),
+        }
+    }
+
+    let impls = groups
+        .into_iter()
+        .map(|(_, modules)| codegen_dyn_module(&modules))
+        .collect::<ChassisResult<Vec<TokenStream2>>>()?;
+    Ok(quote! { #(#impls)* })
+}
+
+fn codegen_dyn_module(modules: &[&Module]) -> ChassisResult<TokenStream2> {
+    let name = &modules[0].name;
+    let binds = modules
+        .iter()
+        .flat_map(|module| &module.bindings)
+        .map(codegen_dyn_binding)
+        .collect::<ChassisResult<Vec<TokenStream2>>>()?;
+
+    // the module type may only exist when one of its impl blocks is enabled
+    let cfg = modules
+        .iter()
+        .map(|module| module.cfg.clone())
+        .collect::<Option<Vec<CfgExpr>>>()
+        .map(|cfgs| {
+            let cfg = CfgExpr::Any(cfgs);
+            quote! { #[cfg(#cfg)] }
+        });
+
+    Ok(quote! {
+        #cfg
+        impl dyn_chassis::Module for #name {
+            fn configure(&self, __binder__: &mut dyn_chassis::Binder) {
+                #(#binds)*
+            }
+        }
+    })
+}
+
+/// Key type and expression to resolve a dependency of type `ty` from a dyn-chassis `injector`.
+///
+/// `Arc<T>` is resolved from the binding of `T`, other types are cloned from their binding.
+/// The expression panics if the key is not bound.
+pub fn codegen_dyn_resolve(
+    ty: &syn::Type,
+    injector: &TokenStream2,
+) -> ChassisResult<(TokenStream2, TokenStream2)> {
+    check_nameable(ty)?;
+    let inject_type = parse_inject_type(ty);
+    Ok(match inject_type.wrapper {
+        Some(WrapperType::Arc) => {
+            let inner = &inject_type.inner_ty;
+            (
+                quote! { #inner },
+                quote! {
+                    #injector.resolve_to::<#inner, ::std::sync::Arc<#inner>>(
+                        dyn_chassis::TypedKey::new()
+                    )
+                },
+            )
+        }
+        _ => (
+            quote! { #ty },
+            quote! {
+                <#ty as ::std::clone::Clone>::clone(
+                    &#injector.resolve_to::<#ty, ::std::sync::Arc<#ty>>(
+                        dyn_chassis::TypedKey::new()
+                    )
+                )
+            },
+        ),
+    })
+}
+
+fn codegen_dyn_binding(binding: &Binding) -> ChassisResult<TokenStream2> {
+    let implementation = &binding.implementation;
+    let module = &implementation.module;
+    let func = &implementation.func;
+    let func_name = func.to_string();
+    let rty = &implementation.rty;
+    check_nameable(rty)?;
+
+    let (dep_keys, dep_impls): (Vec<TokenStream2>, Vec<TokenStream2>) = implementation
+        .injection_point
+        .deps
+        .iter()
+        .map(|dep| codegen_dyn_resolve(&dep.ty, &quote! { __injector__ }))
+        .collect::<ChassisResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    let create = match implementation.binding_type {
+        BindingType::Factory => quote! { #module::#func(#(#dep_impls),*) },
+        BindingType::Linked => quote! { { let linked: #rty = #(#dep_impls)*; linked } },
+        // const, static and default bindings are not part of a module
+        BindingType::Constant | BindingType::Static | BindingType::Default => {
+            return Err(ChassisError::InternalError(format!(
+                "Unexpected binding `{}` in a dyn-chassis module",
+                func_name
+            )))
+        }
+    };
+    let scope = if implementation.singleton {
+        quote! { .in_singleton() }
+    } else {
+        TokenStream2::new()
+    };
+
+    let inject_type = parse_inject_type(rty);
+    let (bound_ty, factory) = match inject_type.wrapper {
+        Some(WrapperType::Arc) => (&inject_type.inner_ty, quote! { to_arc_factory }),
+        _ => (rty, quote! { to_factory }),
+    };
+
+    let cfg = binding.cfg.as_ref().map(|cfg| quote! { #[cfg(#cfg)] });
+    Ok(quote! {
+        #cfg
+        {
+            __binder__.bind::<#bound_ty>()#scope.#factory(
+                move |__injector__: &dyn_chassis::Injector| #create,
+                dyn_chassis::meta::InjectionPoint::for_module_function(
+                    #func_name,
+                    &[#(dyn_chassis::Key::new::<#dep_keys>()),*],
+                ),
+            );
+        }
+    })
+}
+
+/// `impl Trait` types can not be used as binding keys of dyn-chassis.
+fn check_nameable(ty: &syn::Type) -> ChassisResult<()> {
+    if let syn::Type::ImplTrait(_) = ty {
+        return Err(ChassisError::IllegalInput(
+            "`impl Trait` types can not be bound in a dyn-chassis module".to_string(),
+            ty.span(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,179 @@
+use proc_macro2::TokenStream as TokenStream2;
+
+pub struct ComponentField {
+    name: syn::Ident,
+    ty: syn::Type,
+    init: TokenStream2,
+    dispose: Option<syn::Path>,
+}
+
+pub struct ComponentBuilder {
+    fields: Vec<ComponentField>,
+    fallback: Option<syn::Path>,
+    vis: Option<syn::Visibility>,
+    debug_singleton_types: Option<Vec<String>>,
+}
+
+impl ComponentBuilder {
+    pub fn new() -> Self {
+        Self {
+            fields: vec![],
+            fallback: None,
+            vis: None,
+            debug_singleton_types: None,
+        }
+    }
+
+    /// Visibility of struct and constructor, `pub` by default.
+    pub fn vis(&mut self, vis: syn::Visibility) -> &mut Self {
+        self.vis = Some(vis);
+        self
+    }
+
+    /// Implement `Debug` listing the types of the built singletons, not their values.
+    pub fn debug(&mut self, singleton_types: Vec<String>) -> &mut Self {
+        self.debug_singleton_types = Some(singleton_types);
+        self
+    }
+
+    /// Take injector of type `ty` in constructor to resolve keys at runtime.
+    pub fn fallback(&mut self, ty: syn::Path) -> &mut Self {
+        self.fallback = Some(ty);
+        self
+    }
+
+    /// Add field to component.
+    ///
+    /// Fields have to be added in dependency order: a field may only depend on fields added
+    /// before it.
+    pub fn field(
+        &mut self,
+        name: syn::Ident,
+        ty: syn::Type,
+        init: TokenStream2,
+        dispose: Option<syn::Path>,
+    ) -> &mut Self {
+        self.fields.push(ComponentField {
+            name,
+            ty,
+            init,
+            dispose,
+        });
+        self
+    }
+
+    pub fn build(&mut self, impl_name: &syn::Ident) -> TokenStream2 {
+        // Rust drops fields in declaration order, so dependents have to be declared first
+        let singleton_defs: Vec<TokenStream2> = self
+            .fields
+            .iter()
+            .rev()
+            .map(|field| {
+                let name = &field.name;
+                let ty = &field.ty;
+                quote! {
+                    #name: #ty
+                }
+            })
+            .collect();
+        let singleton_inits: Vec<TokenStream2> = self
+            .fields
+            .iter()
+            .map(|field| {
+                let name = &field.name;
+                let init = &field.init;
+                let ty = &field.ty;
+                quote! {
+                    let #name: #ty = #init;
+                }
+            })
+            .collect();
+        let singleton_disposes: Vec<TokenStream2> = self
+            .fields
+            .iter()
+            .rev()
+            .filter_map(|field| {
+                let name = &field.name;
+                field.dispose.as_ref().map(|dispose| {
+                    quote! {
+                        #dispose(&mut self.#name);
+                    }
+                })
+            })
+            .collect();
+
+        let singleton_names: Vec<&syn::Ident> =
+            self.fields.iter().map(|field| &field.name).collect();
+
+        // fallback injector is declared last, so it is dropped after all singletons
+        let (fallback_def, fallback_param, fallback_name) = match &self.fallback {
+            Some(ty) => (
+                quote! { fallback: #ty, },
+                quote! { fallback: #ty },
+                quote! { fallback, },
+            ),
+            None => (
+                TokenStream2::new(),
+                TokenStream2::new(),
+                TokenStream2::new(),
+            ),
+        };
+
+        let drop_impl = if singleton_disposes.is_empty() {
+            TokenStream2::new()
+        } else {
+            quote! {
+                impl Drop for #impl_name {
+                    fn drop(&mut self) {
+                        #(#singleton_disposes)*
+                    }
+                }
+            }
+        };
+
+        let debug_impl = match &self.debug_singleton_types {
+            Some(singleton_types) => {
+                let name = impl_name.to_string();
+                quote! {
+                    impl ::std::fmt::Debug for #impl_name {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.debug_struct(#name)
+                                .field("singleton_types", &(&[#(#singleton_types),*] as &[&str]))
+                                .finish()
+                        }
+                    }
+                }
+            }
+            None => TokenStream2::new(),
+        };
+
+        let vis = match &self.vis {
+            Some(vis) => quote! { #vis },
+            None => quote! { pub },
+        };
+
+        quote! {
+            #vis struct #impl_name {
+                #(#singleton_defs,)*
+                #fallback_def
+            }
+
+            impl #impl_name {
+                #vis fn new(#fallback_param) -> Self {
+                    #(#singleton_inits)*
+                    Self {
+                        #(#singleton_names,)*
+                        #fallback_name
+                    }
+                }
+
+                /// Tear down all singletons in reverse dependency order.
+                #vis fn close(self) {}
+            }
+
+            #drop_impl
+
+            #debug_impl
+        }
+    }
+}
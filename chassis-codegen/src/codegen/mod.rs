@@ -0,0 +1,396 @@
+use std::ops::Deref;
+
+use proc_macro2::Span;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::Ident;
+
+use singletons::SINGLETON_FIELD_PREFIX;
+
+use crate::cfg::{CfgCombination, MAX_CFG_PREDICATES};
+use crate::codegen::context::{CodegenContext, CodegenEnv};
+use crate::codegen::dyn_module::codegen_dyn_resolve;
+use crate::codegen::generator::ComponentBuilder;
+use crate::codegen::plan::resolution_plan;
+use crate::codegen::singletons::find_singletons;
+use crate::codegen::traits::{is_supertrait, resolve_component_traits};
+use crate::container::IocContainer;
+use crate::errors::{codegen_compile_errors, ChassisError, ChassisResult};
+use crate::key::StaticKey;
+use crate::model::{BindingType, Block, ComponentTrait, Implementation, Module, Request};
+use crate::syn_ext::IdentExt;
+use crate::utils::to_tokens;
+
+mod context;
+mod dyn_module;
+mod generator;
+mod plan;
+mod singletons;
+mod traits;
+
+pub use dyn_module::codegen_dyn_modules;
+
+const TRAIT_IMPL_SUFFIX: &str = "Impl";
+const REUSABLE_VAR_PREFIX: &str = "reusable_of_";
+
+/// Creates implementations for all components of the integration `block`.
+///
+/// For every combination of cfg predicates used in the block an own dependency graph is
+/// generated and gated with a cfg attribute for that combination. Errors in a combination are
+/// only raised when that combination is compiled.
+pub fn codegen_block(block: &Block) -> ChassisResult<TokenStream2> {
+    check_includes(&block.modules)?;
+
+    let mut predicates: Vec<String> = vec![];
+    let bindings = block
+        .modules
+        .iter()
+        .flat_map(|module| &module.bindings)
+        .chain(&block.constants);
+    let cfgs = bindings
+        .filter_map(|binding| binding.cfg.as_ref())
+        .chain(block.components.iter().filter_map(|comp| comp.cfg.as_ref()));
+    for cfg in cfgs {
+        cfg.collect_predicates(&mut predicates);
+    }
+    if predicates.len() > MAX_CFG_PREDICATES {
+        return Err(ChassisError::IllegalInput(
+            format!(
+                "Too many distinct cfg predicates: only {} are supported",
+                MAX_CFG_PREDICATES
+            ),
+            Span::call_site(),
+        ));
+    }
+
+    let mut result = TokenStream2::new();
+    for combination in CfgCombination::all(&predicates) {
+        let code = codegen_combination(block, &combination);
+        let code = if predicates.is_empty() {
+            code?
+        } else {
+            code.unwrap_or_else(codegen_compile_errors)
+        };
+        result.extend(combination.gate_items(code)?);
+    }
+    Ok(result)
+}
+
+fn codegen_combination(block: &Block, combination: &CfgCombination) -> ChassisResult<TokenStream2> {
+    let components = block
+        .components
+        .iter()
+        .filter(|comp| combination.enables(comp.cfg.as_ref()))
+        .map(|comp| {
+            let mut container = IocContainer::new();
+            if comp.fallback.is_some() {
+                container.enable_fallback();
+            }
+            for module in component_modules(comp, &block.modules)? {
+                container.add_module(module, combination)?;
+            }
+            container.add_bindings(&block.constants, combination)?;
+            container.add_default_bindings(&comp.default_bindings)?;
+            codegen_component_impl(comp, &block.components, &container)
+        })
+        .collect::<ChassisResult<Vec<TokenStream2>>>()?;
+    Ok(quote! { #(#components)* })
+}
+
+/// Every module included by another module has to be defined in the block, even if no
+/// component lists its modules.
+fn check_includes(modules: &[Module]) -> ChassisResult<()> {
+    let keys = modules
+        .iter()
+        .map(|module| StaticKey::try_new(&syn::Type::Path(module.name.clone())))
+        .collect::<ChassisResult<Vec<StaticKey>>>()?;
+    for include in modules.iter().flat_map(|module| &module.includes) {
+        let key = StaticKey::try_new(&syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: include.clone(),
+        }))?;
+        if !keys.contains(&key) {
+            return Err(ChassisError::IllegalInput(
+                format!("Unknown module `{}`", key),
+                include.span(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Modules used by `component` including all transitively included modules.
+fn component_modules<'a>(
+    component: &ComponentTrait,
+    modules: &'a [Module],
+) -> ChassisResult<Vec<&'a Module>> {
+    let mut todo: Vec<syn::Path> = match &component.modules {
+        Some(names) => names.iter().rev().cloned().collect(),
+        None => return Ok(modules.iter().collect()),
+    };
+
+    let mut result: Vec<&Module> = vec![];
+    let mut seen: Vec<StaticKey> = vec![];
+    while let Some(name) = todo.pop() {
+        let key = StaticKey::try_new(&syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: name.clone(),
+        }))?;
+        if seen.contains(&key) {
+            continue;
+        }
+
+        // a module can consist of multiple impl blocks
+        let mut found = false;
+        for module in modules {
+            if StaticKey::try_new(&syn::Type::Path(module.name.clone()))? == key {
+                found = true;
+                result.push(module);
+                todo.extend(module.includes.iter().rev().cloned());
+            }
+        }
+        if !found {
+            return Err(ChassisError::IllegalInput(
+                format!("Unknown module `{}`", key),
+                name.span(),
+            ));
+        }
+        seen.push(key);
+    }
+    Ok(result)
+}
+
+fn codegen_component_impl(
+    component: &ComponentTrait,
+    components: &[ComponentTrait],
+    container: &IocContainer,
+) -> ChassisResult<TokenStream2> {
+    if !component.generics.is_empty() {
+        // generic components are only implemented as supertraits of other components
+        return if is_supertrait(component, components) {
+            Ok(TokenStream2::new())
+        } else {
+            Err(ChassisError::IllegalInput(
+                format!(
+                    "Generic component `{}` has to be a supertrait of another component",
+                    component.trait_name
+                ),
+                component.trait_name.span(),
+            ))
+        };
+    }
+
+    let traits = resolve_component_traits(component, components)?;
+    let singletons = find_singletons(
+        traits.iter().flat_map(|trait_impl| &trait_impl.requests),
+        container,
+    )?;
+    let mut component_builder = ComponentBuilder::new();
+    if let Some(fallback) = &component.fallback {
+        component_builder.fallback(fallback.clone());
+    }
+    if let Some(vis) = &component.vis {
+        component_builder.vis(vis.clone());
+    }
+    if component.derive_debug {
+        component_builder.debug(singletons.iter().map(StaticKey::to_string).collect());
+    }
+
+    let trait_impls = traits
+        .into_iter()
+        .map(|trait_impl| {
+            let impl_items = trait_impl
+                .requests
+                .into_iter()
+                .map(|request| codegen_provider_fn(request, container))
+                .collect::<ChassisResult<Vec<TokenStream2>>>()?;
+            Ok((trait_impl.path, impl_items))
+        })
+        .collect::<ChassisResult<Vec<(syn::Path, Vec<TokenStream2>)>>>()?;
+
+    // codegen singletons
+    for singleton in singletons {
+        let ctx = CodegenContext::new(container, CodegenEnv::Ctor);
+        let binding = ctx.enter_resolving(&singleton, Span::call_site())?.unwrap();
+
+        let field = binding.func.prepend(SINGLETON_FIELD_PREFIX);
+        let code = codegen_impl(binding, &ctx, true)?;
+        component_builder.field(field, binding.rty.clone(), code, binding.dispose.clone());
+    }
+
+    // codegen component
+    let impl_name = component
+        .impl_name
+        .clone()
+        .unwrap_or_else(|| component.trait_name.append(TRAIT_IMPL_SUFFIX));
+    let component_struct = component_builder.build(&impl_name);
+    let trait_impls = trait_impls.iter().map(|(trait_path, impl_items)| {
+        quote! {
+            impl #trait_path for #impl_name {
+                #(#impl_items)*
+            }
+        }
+    });
+    Ok(quote! {
+        #component_struct
+
+        #(#trait_impls)*
+    })
+}
+
+/// Key to resolve for `request`
+///
+/// Is the key of an explicitly linked provider or the request type itself.
+fn request_key(request: &Request, container: &IocContainer) -> ChassisResult<StaticKey> {
+    if let Some(provider) = &request.provider {
+        return container.find_provider(provider)?.cloned().ok_or_else(|| {
+            ChassisError::IllegalInput(
+                format!("Provider `{}` not found", to_tokens(provider)),
+                provider.span(),
+            )
+        });
+    }
+
+    if let syn::Type::ImplTrait(_) = &request.ty {
+        if container.resolve(&request.key).is_none() {
+            return Err(ChassisError::IllegalInput(
+                format!(
+                    "No provider for `{}` found, \
+                     link one with `#[provided_by(Module::function)]`",
+                    request.key
+                ),
+                request.ty.span(),
+            ));
+        }
+    }
+
+    Ok(request.key.clone())
+}
+
+/// Creates function for trait implementation
+fn codegen_provider_fn(request: Request, container: &IocContainer) -> ChassisResult<TokenStream2> {
+    let provider_ctx = CodegenContext::new(container, CodegenEnv::TraitImpl);
+
+    let key = request_key(&request, container)?;
+    let code = codegen_for_key(&key, &request.ty, &provider_ctx)?;
+    let plan = resolution_plan(&key, container)?;
+    let hoisted = provider_ctx.hoisted_statements();
+    let rty = &request.ty; // TODO: clone with call_span
+    let name = &request.name;
+    let span = request.name.span(); // TODO: use Signature as span
+
+    Ok(quote_spanned! {span=>
+        #[doc = #plan]
+        fn #name(&self) -> #rty {
+            #(#hoisted)*
+            #code
+        }
+    })
+}
+
+/// Creates expression for getting `key` of type `ty`
+fn codegen_for_key(
+    key: &StaticKey,
+    ty: &syn::Type,
+    ctx: &CodegenContext,
+) -> ChassisResult<TokenStream2> {
+    let scope = ctx.enter_resolving(key, Span::call_site())?;
+
+    if let Some(binding) = scope.deref() {
+        codegen_impl(binding, ctx, false)
+    } else if let Some(fallback) = ctx.fallback() {
+        Ok(codegen_dyn_resolve(ty, &fallback)?.1)
+    } else {
+        Err(ctx.missing_dependency(key))
+    }
+}
+
+/// Name of the hoisted variable for a reusable `implementation`.
+///
+/// Contains the module path because provider functions of different modules can share a name.
+fn reusable_var_name(implementation: &Implementation) -> Ident {
+    let module: String = implementation
+        .module
+        .as_ref()
+        .map(|module| to_tokens(module).to_string())
+        .unwrap_or_default()
+        .chars()
+        .flat_map(|c| match c {
+            c if c.is_uppercase() => vec!['_', c.to_ascii_lowercase()],
+            c if c.is_alphanumeric() => vec![c],
+            _ => vec!['_'],
+        })
+        .collect::<String>()
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    let func = &implementation.func;
+    Ident::new(
+        &format!("{}{}_{}", REUSABLE_VAR_PREFIX, module, func),
+        func.span(),
+    )
+}
+
+/// Creates expression for `implementation`
+fn codegen_impl(
+    implementation: &Implementation,
+    ctx: &CodegenContext,
+    force_impl: bool,
+) -> ChassisResult<TokenStream2> {
+    let module = &implementation.module;
+    let func = &implementation.func;
+
+    if implementation.singleton && !force_impl {
+        let singleton_field = func.prepend(SINGLETON_FIELD_PREFIX);
+        return Ok(match ctx.env() {
+            CodegenEnv::Ctor => quote! {
+                #singleton_field.clone()
+            },
+            CodegenEnv::TraitImpl => quote! {
+                self.#singleton_field.clone() // TODO: use ref when ref needed
+            },
+        });
+    }
+
+    // reusable instances are only shared in component methods
+    let reusable_var = reusable_var_name(implementation);
+    let reusable = implementation.reusable && ctx.env() == CodegenEnv::TraitImpl;
+    if reusable && ctx.is_hoisted(&reusable_var) {
+        return Ok(quote! { #reusable_var.clone() });
+    }
+
+    let dep_impls = implementation
+        .injection_point
+        .deps
+        .iter()
+        .map(|dep| codegen_for_key(&dep.key, &dep.ty, ctx))
+        .collect::<ChassisResult<Vec<TokenStream2>>>()?;
+
+    let rty = &implementation.rty;
+    let code = match implementation.binding_type {
+        BindingType::Factory => quote! {
+            #module::#func(#(#dep_impls),*)
+        },
+        BindingType::Constant => quote! { #func },
+        BindingType::Static => quote! { &#func },
+        BindingType::Default => quote! { <#rty as ::std::default::Default>::default() },
+        BindingType::Linked => {
+            quote! {
+                { let linked: #rty = #(#dep_impls)*; linked }
+            }
+        }
+    };
+
+    if reusable {
+        ctx.hoist(
+            reusable_var.clone(),
+            quote! {
+                let #reusable_var: #rty = #code;
+            },
+        );
+        Ok(quote! { #reusable_var.clone() })
+    } else {
+        Ok(code)
+    }
+}
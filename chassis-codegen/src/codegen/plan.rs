@@ -0,0 +1,119 @@
+use std::fmt::Write;
+
+use crate::container::IocContainer;
+use crate::errors::ChassisResult;
+use crate::key::StaticKey;
+use crate::model::{BindingType, Implementation};
+use crate::utils::to_tokens;
+
+/// Describe how `key` is resolved as markdown list for the documentation of a component method.
+///
+/// Has to be called after code generation succeeded, so that dependencies are known to be
+/// acyclic.
+pub fn resolution_plan(key: &StaticKey, container: &IocContainer) -> ChassisResult<String> {
+    let mut plan = String::from("Resolution plan:\n\n");
+    plan_for_key(key, container, 0, &mut plan)?;
+    Ok(plan)
+}
+
+fn plan_for_key(
+    key: &StaticKey,
+    container: &IocContainer,
+    depth: usize,
+    plan: &mut String,
+) -> ChassisResult<()> {
+    write!(plan, "{:indent$}* `{}` ", "", key, indent = depth * 2)?;
+
+    let implementation = match container.resolve(key) {
+        Some(implementation) => implementation,
+        None => {
+            writeln!(plan, "from fallback injector")?;
+            return Ok(());
+        }
+    };
+
+    let provider = provider_name(implementation);
+    if implementation.singleton {
+        // created with the component
+        writeln!(plan, "from singleton of `{}`", provider)?;
+        return Ok(());
+    }
+
+    let reused = if implementation.reusable {
+        " (reused in this call)"
+    } else {
+        ""
+    };
+    match implementation.binding_type {
+        BindingType::Factory => writeln!(plan, "from `{}`{}", provider, reused)?,
+        BindingType::Linked => writeln!(plan, "bound by `{}`{} to", provider, reused)?,
+        BindingType::Constant => writeln!(plan, "from const `{}`", implementation.func)?,
+        BindingType::Static => writeln!(plan, "from static `{}`", implementation.func)?,
+        BindingType::Default => writeln!(plan, "from `Default::default()`")?,
+    }
+
+    implementation
+        .injection_point
+        .deps
+        .iter()
+        .try_for_each(|dep| plan_for_key(&dep.key, container, depth + 1, plan))
+}
+
+fn provider_name(implementation: &Implementation) -> String {
+    format!(
+        "{}::{}",
+        to_tokens(&implementation.module)
+            .to_string()
+            .replace(' ', ""),
+        implementation.func
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg::CfgCombination;
+    use crate::parse::parse_block;
+
+    #[test]
+    fn check_plan() {
+        let file: syn::File = syn::parse2(quote! {
+            impl DemoModule {
+                #[singleton]
+                pub fn provide_printer() -> Rc<StdoutPrinter> {
+                    Rc::new(StdoutPrinter)
+                }
+
+                #[binds]
+                fn printer(printer: Rc<StdoutPrinter>) -> Rc<dyn Printer>;
+
+                #[reusable]
+                pub fn provide_message() -> Message {
+                    Message
+                }
+
+                pub fn provide_greeter(message: Message, printer: Rc<dyn Printer>) -> Greeter {
+                    Greeter
+                }
+            }
+        })
+        .unwrap();
+        let mut items = file.items;
+        let block = parse_block(&mut items).unwrap();
+        let mut container = IocContainer::new();
+        let combination = CfgCombination::all(&[]).next().unwrap();
+        container
+            .add_module(&block.modules[0], &combination)
+            .unwrap();
+
+        let key = StaticKey::try_new(&syn::parse_str("Greeter").unwrap()).unwrap();
+        assert_eq!(
+            "Resolution plan:\n\n\
+             * `Greeter` from `DemoModule::provide_greeter`\n\
+             \x20 * `Message` from `DemoModule::provide_message` (reused in this call)\n\
+             \x20 * `Rc<dyn Printer>` bound by `DemoModule::printer` to\n\
+             \x20   * `Rc<StdoutPrinter>` from singleton of `DemoModule::provide_printer`\n",
+            resolution_plan(&key, &container).unwrap()
+        );
+    }
+}
@@ -4,10 +4,11 @@ use proc_macro2::Span;
 use syn::spanned::Spanned;
 
 use crate::codegen::context::{CodegenContext, CodegenEnv};
+use crate::codegen::request_key;
 use crate::container::IocContainer;
-use crate::errors::{ChassisError, ChassisResult};
+use crate::errors::ChassisResult;
 use crate::key::StaticKey;
-use crate::model::{ComponentTrait, Request};
+use crate::model::Request;
 
 pub const SINGLETON_FIELD_PREFIX: &str = "singleton_of_";
 
@@ -17,7 +18,8 @@ fn singletons_for_provider(
     singletons: &mut Vec<StaticKey>,
 ) -> ChassisResult<()> {
     let provider_ctx = CodegenContext::new(container, CodegenEnv::TraitImpl);
-    singletons_for_key(&request.key, request.ty.span(), &provider_ctx, singletons)
+    let key = request_key(request, container)?;
+    singletons_for_key(&key, request.ty.span(), &provider_ctx, singletons)
 }
 
 fn singletons_for_key(
@@ -43,23 +45,24 @@ fn singletons_for_key(
             singletons.push(key.clone());
         }
 
+        Ok(())
+    } else if ctx.fallback().is_some() {
         Ok(())
     } else {
-        Err(ChassisError::MissingDependency(ctx.dependency_chain()))
+        Err(ctx.missing_dependency(key))
     }
 }
 
-/// Find singletons recursively in `component`.
+/// Find singletons recursively in the `requests` of a component.
 ///
 /// Returns list with singletons in that order, that entries do not depend on previous entries.
-pub fn find_singletons(
-    component: &ComponentTrait,
+pub fn find_singletons<'a>(
+    requests: impl IntoIterator<Item = &'a Request>,
     container: &IocContainer,
 ) -> ChassisResult<Vec<StaticKey>> {
     let mut singletons: Vec<StaticKey> = Vec::new();
-    component
-        .requests
-        .iter()
+    requests
+        .into_iter()
         .try_for_each(|request| singletons_for_provider(request, container, &mut singletons))?;
     Ok(singletons)
 }
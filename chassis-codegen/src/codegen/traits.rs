@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+
+use syn::spanned::Spanned;
+use syn::visit_mut::{self, VisitMut};
+use syn::{GenericArgument, PathArguments};
+
+use crate::errors::{ChassisError, ChassisResult};
+use crate::key::StaticKey;
+use crate::model::{ComponentTrait, Request};
+use crate::utils::to_tokens;
+
+/// Component trait to implement for a component with concrete generic arguments.
+pub struct TraitImpl {
+    /// path to trait including generic arguments
+    pub path: syn::Path,
+
+    /// requests with generic parameters replaced
+    pub requests: Vec<Request>,
+}
+
+/// Replaces generic type parameters with concrete types.
+struct Substitution<'a>(&'a HashMap<syn::Ident, syn::Type>);
+
+impl<'a> VisitMut for Substitution<'a> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(path) = ty {
+            if path.qself.is_none() {
+                if let Some(ident) = path.path.get_ident() {
+                    if let Some(replacement) = self.0.get(ident) {
+                        *ty = replacement.clone();
+                        return;
+                    }
+                }
+            }
+        }
+
+        visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+fn substitute_request(
+    request: &Request,
+    substitution: &HashMap<syn::Ident, syn::Type>,
+) -> ChassisResult<Request> {
+    let mut ty = request.ty.clone();
+    Substitution(substitution).visit_type_mut(&mut ty);
+    Ok(Request {
+        key: StaticKey::try_new(&ty)?,
+        ty,
+        ..request.clone()
+    })
+}
+
+fn generic_arguments(path: &syn::Path) -> ChassisResult<Vec<syn::Type>> {
+    match &path.segments.last().unwrap().arguments {
+        PathArguments::None => Ok(vec![]),
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .map(|arg| match arg {
+                GenericArgument::Type(ty) => Ok(ty.clone()),
+                _ => Err(ChassisError::IllegalInput(
+                    "Only type arguments are supported for component traits".to_string(),
+                    arg.span(),
+                )),
+            })
+            .collect(),
+        PathArguments::Parenthesized(args) => Err(ChassisError::IllegalInput(
+            "Only type arguments are supported for component traits".to_string(),
+            args.span(),
+        )),
+    }
+}
+
+/// Name of the component trait referred to by supertrait `path`.
+///
+/// Components are referred to by their name in the integration module, paths to other
+/// modules would denote other traits.
+fn component_name(path: &syn::Path) -> Option<&syn::Ident> {
+    match path.segments.len() {
+        1 if path.leading_colon.is_none() => Some(&path.segments[0].ident),
+        _ => None,
+    }
+}
+
+/// Whether `component` is a supertrait of one of `components`.
+pub fn is_supertrait(component: &ComponentTrait, components: &[ComponentTrait]) -> bool {
+    components
+        .iter()
+        .flat_map(|other| &other.supertraits)
+        .any(|supertrait| component_name(supertrait) == Some(&component.trait_name))
+}
+
+/// Find component trait for supertrait `path` in `components`.
+fn find_component<'a>(
+    path: &syn::Path,
+    components: &'a [ComponentTrait],
+) -> ChassisResult<&'a ComponentTrait> {
+    let ident = component_name(path).ok_or_else(|| {
+        ChassisError::IllegalInput(
+            format!(
+                "Supertrait `{}` has to be a component of this integration module named \
+                 without a path",
+                to_tokens(path)
+            ),
+            path.span(),
+        )
+    })?;
+    components
+        .iter()
+        .find(|component| &component.trait_name == ident)
+        .ok_or_else(|| {
+            ChassisError::IllegalInput(
+                format!(
+                    "Supertrait `{}` is not a component of this integration module",
+                    to_tokens(path)
+                ),
+                path.span(),
+            )
+        })
+}
+
+/// Collect `component` and all its supertraits with their requests.
+///
+/// Generic parameters of supertraits are replaced by the arguments given in the supertrait
+/// bound, so `trait AppComponent: RepoComponent<User>` results in the requests of
+/// `RepoComponent` instantiated for `User`.
+pub fn resolve_component_traits(
+    component: &ComponentTrait,
+    components: &[ComponentTrait],
+) -> ChassisResult<Vec<TraitImpl>> {
+    let mut result: Vec<TraitImpl> = vec![];
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut todo: Vec<(syn::Path, &ComponentTrait, HashMap<syn::Ident, syn::Type>)> = vec![(
+        component.trait_name.clone().into(),
+        component,
+        HashMap::new(),
+    )];
+
+    while let Some((path, component, substitution)) = todo.pop() {
+        if !seen.insert(to_tokens(&path).to_string()) {
+            continue;
+        }
+
+        for supertrait in &component.supertraits {
+            let mut supertrait = supertrait.clone();
+            Substitution(&substitution).visit_path_mut(&mut supertrait);
+
+            let super_component = find_component(&supertrait, components)?;
+            let args = generic_arguments(&supertrait)?;
+            if args.len() != super_component.generics.len() {
+                return Err(ChassisError::IllegalInput(
+                    format!(
+                        "Expected {} type arguments for component `{}`",
+                        super_component.generics.len(),
+                        super_component.trait_name
+                    ),
+                    supertrait.span(),
+                ));
+            }
+
+            let super_substitution = super_component.generics.iter().cloned().zip(args).collect();
+            todo.push((supertrait, super_component, super_substitution));
+        }
+
+        result.push(TraitImpl {
+            requests: component
+                .requests
+                .iter()
+                .map(|request| substitute_request(request, &substitution))
+                .collect::<ChassisResult<Vec<Request>>>()?,
+            path,
+        });
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+
+use crate::cfg::CfgCombination;
+use crate::errors::{ChassisError, ChassisResult};
+use crate::key::StaticKey;
+use crate::model::{Binding, Implementation, Module};
+
+pub struct IocContainer {
+    bindings: HashMap<StaticKey, Implementation>,
+
+    /// unknown keys are resolved at runtime by a fallback injector
+    fallback: bool,
+}
+
+impl IocContainer {
+    pub fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            fallback: false,
+        }
+    }
+
+    /// Resolve keys without binding at runtime with a fallback injector.
+    pub fn enable_fallback(&mut self) {
+        self.fallback = true;
+    }
+
+    pub fn has_fallback(&self) -> bool {
+        self.fallback
+    }
+
+    pub fn resolve(&self, key: &StaticKey) -> Option<&Implementation> {
+        self.bindings.get(key)
+    }
+
+    /// Keys and spans of bindings similar to `key`, most similar first.
+    pub fn near_matches(&self, key: &StaticKey) -> Vec<(String, Span)> {
+        let mut matches: Vec<(usize, String, Span)> = self
+            .bindings
+            .iter()
+            .filter_map(|(other, implementation)| {
+                key.similarity(other)
+                    .map(|distance| (distance, other.to_string(), implementation.rty.span()))
+            })
+            .collect();
+        matches.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+        matches
+            .into_iter()
+            .map(|(_, key, span)| (key, span))
+            .collect()
+    }
+
+    /// Find binding of provider function `path` like `Module::provide_something`.
+    pub fn find_provider(&self, path: &syn::Path) -> ChassisResult<Option<&StaticKey>> {
+        let mut module_path = path.clone();
+        let func = match module_path.segments.pop() {
+            Some(func) if !module_path.segments.is_empty() => func.into_value().ident,
+            _ => {
+                return Err(ChassisError::IllegalInput(
+                    "Expected path to provider function like `Module::function`".to_string(),
+                    path.span(),
+                ))
+            }
+        };
+        // remove trailing `::`
+        let module_ident = module_path.segments.pop().unwrap().into_value();
+        module_path.segments.push(module_ident);
+
+        let module = StaticKey::try_new(&syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: module_path,
+        }))?;
+        for (key, implementation) in &self.bindings {
+            if let Some(impl_module) = &implementation.module {
+                if implementation.func == func && StaticKey::try_new(impl_module)? == module {
+                    return Ok(Some(key));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn add(&mut self, key: StaticKey, implementation: Implementation) -> ChassisResult<()> {
+        let rty = implementation.rty.clone();
+        let other = self.bindings.insert(key.clone(), implementation);
+        if let Some(other) = other {
+            return Err(ChassisError::DuplicateImplementation(
+                key.to_string(),
+                rty.span(),
+                other.rty.span(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Add all bindings of `module` that are enabled in `combination`.
+    pub fn add_module(
+        &mut self,
+        module: &Module,
+        combination: &CfgCombination,
+    ) -> ChassisResult<()> {
+        self.add_bindings(&module.bindings, combination)
+    }
+
+    /// Add all `bindings` that are enabled in `combination`.
+    pub fn add_bindings(
+        &mut self,
+        bindings: &[Binding],
+        combination: &CfgCombination,
+    ) -> ChassisResult<()> {
+        for binding in bindings {
+            if combination.enables(binding.cfg.as_ref()) {
+                self.add(binding.key.clone(), binding.implementation.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `bindings` whose keys are not bound yet.
+    pub fn add_default_bindings(&mut self, bindings: &[Binding]) -> ChassisResult<()> {
+        for binding in bindings {
+            if !self.bindings.contains_key(&binding.key) {
+                self.add(binding.key.clone(), binding.implementation.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for IocContainer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("IocContainer")
+            .field(&self.bindings.keys().cloned().collect::<Vec<StaticKey>>())
+            .finish()
+    }
+}
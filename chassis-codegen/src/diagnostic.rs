@@ -1,4 +1,5 @@
 use std::convert::TryFrom;
+use std::fmt;
 
 use proc_macro2::Span;
 use proc_macro2::TokenStream as TokenStream2;
@@ -52,6 +53,13 @@ impl Diagnostic {
 
     #[cfg(not(nightly_diagnostics))]
     pub fn emit(self) -> TokenStream2 {
+        self.into_compile_error()
+    }
+
+    /// Create `compile_error!` invocations, that only fail when they are compiled.
+    ///
+    /// Warnings and other non-errors are ignored.
+    pub fn into_compile_error(self) -> TokenStream2 {
         use std::convert::TryInto;
 
         let err: Result<syn::parse::Error, ()> = self.try_into();
@@ -80,6 +88,16 @@ impl Diagnostic {
     }
 }
 
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.single_error_message())?;
+        for hint in &self.hints {
+            write!(f, "\n  {}", hint.single_error_message())?;
+        }
+        Ok(())
+    }
+}
+
 impl TryFrom<Diagnostic> for syn::parse::Error {
     type Error = ();
 
@@ -5,12 +5,13 @@ use proc_macro2::TokenStream as TokenStream2;
 
 use crate::diagnostic::{Diagnostic, DiagnosticExt};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ChassisError {
     InternalError(String),
     IllegalInput(String, Span),
     CyclicDependency(Vec<(String, Span)>),
-    MissingDependency(Vec<(String, Span)>),
+    /// dependency chain and similar keys with the spans of their providers
+    MissingDependency(Vec<(String, Span)>, Vec<(String, Span)>),
     DuplicateImplementation(String, Span, Span),
 }
 
@@ -22,7 +23,27 @@ impl From<fmt::Error> for ChassisError {
     }
 }
 
+impl fmt::Display for ChassisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        diagnostic(self.clone()).fmt(f)
+    }
+}
+
+impl std::error::Error for ChassisError {}
+
 pub fn codegen_errors(err: ChassisError) -> TokenStream2 {
+    diagnostic(err).emit()
+}
+
+/// Create code with `compile_error!` invocations for `err`.
+///
+/// Unlike [codegen_errors] the error is only raised when the code is compiled, so the code can
+/// be placed behind a `#[cfg(...)]` attribute.
+pub fn codegen_compile_errors(err: ChassisError) -> TokenStream2 {
+    diagnostic(err).into_compile_error()
+}
+
+fn diagnostic(err: ChassisError) -> Diagnostic {
     match err {
         ChassisError::InternalError(message) => Span::call_site().error(message),
         ChassisError::IllegalInput(message, span) => span.error(message),
@@ -30,15 +51,20 @@ pub fn codegen_errors(err: ChassisError) -> TokenStream2 {
             format!("Cyclic dependency for `{}`", chain[chain.len() - 1].0),
             chain,
         ),
-        ChassisError::MissingDependency(chain) => error_from_dep_chain(
-            format!("Missing dependency `{}`", chain[chain.len() - 1].0),
-            chain,
-        ),
+        ChassisError::MissingDependency(chain, candidates) => {
+            let mut diagnostic = error_from_dep_chain(
+                format!("Missing dependency `{}`", chain[chain.len() - 1].0),
+                chain,
+            );
+            for (key, span) in candidates {
+                diagnostic = diagnostic.help_in(format!("did you mean `{}`?", key), span);
+            }
+            diagnostic
+        }
         ChassisError::DuplicateImplementation(ty, one, two) => one
             .error(format!("Duplicate implementation for `{}`", ty))
             .help_in("Other implementation found here", two),
     }
-    .emit()
 }
 
 fn error_from_dep_chain(message: String, chain: Vec<(String, Span)>) -> Diagnostic {
@@ -8,6 +8,7 @@ use syn::spanned::Spanned;
 use syn::{GenericArgument, TraitBoundModifier, TypeParamBound};
 
 use crate::errors::{ChassisError, ChassisResult};
+use crate::utils::edit_distance;
 
 /// Key to something to inject.
 ///
@@ -28,6 +29,51 @@ impl StaticKey {
     pub fn type_string(&self) -> &str {
         &self.ty_str
     }
+
+    /// Distance to `other` if `other` is likely meant instead of this key.
+    ///
+    /// Keys are similar when they only differ in smart pointers or references (`Rc<Foo>`
+    /// and `Arc<Foo>`), in the path of the type (`a::Foo` and `b::Foo`) or by a small edit
+    /// distance. Lower values mean more similar keys.
+    pub fn similarity(&self, other: &StaticKey) -> Option<usize> {
+        let distance = edit_distance(&self.ty_str, &other.ty_str);
+        if unqualified(strip_wrappers(&self.ty_str)) == unqualified(strip_wrappers(&other.ty_str))
+            || distance <= self.ty_str.len() / 3
+        {
+            Some(distance)
+        } else {
+            None
+        }
+    }
+}
+
+const WRAPPER_TYPES: &[&str] = &["Rc", "Arc", "Box"];
+
+/// Remove references and smart pointers around the type of key string `ty`.
+fn strip_wrappers(mut ty: &str) -> &str {
+    loop {
+        if let Some(inner) = ty.strip_prefix('&') {
+            let inner = inner.strip_prefix("'static ").unwrap_or(inner);
+            ty = inner.strip_prefix("mut ").unwrap_or(inner);
+            continue;
+        }
+
+        match (ty.find('<'), ty.strip_suffix('>')) {
+            (Some(start), Some(rest)) if WRAPPER_TYPES.contains(&unqualified(&ty[..start])) => {
+                ty = &rest[start + 1..];
+            }
+            _ => return ty,
+        }
+    }
+}
+
+/// Remove path of outermost type of key string `ty`.
+fn unqualified(ty: &str) -> &str {
+    let end = ty.find('<').unwrap_or(ty.len());
+    match ty[..end].rfind("::") {
+        Some(pos) => &ty[pos + 2..],
+        None => ty,
+    }
 }
 
 impl Hash for StaticKey {
@@ -232,17 +278,20 @@ impl ToKeyStr for syn::TypePtr {
 
 impl ToKeyStr for syn::TypeReference {
     fn conv_to_key_str(&self, f: &mut String) -> ChassisResult<()> {
-        if self.lifetime.is_some() {
-            return Err(ChassisError::IllegalInput(
-                "lifetime specifiers are not supported".into(),
-                self.span(),
-            ));
+        f.write_str("&")?;
+        match &self.lifetime {
+            None => {}
+            Some(lifetime) if lifetime.ident == "static" => f.write_str("'static ")?,
+            Some(lifetime) => {
+                return Err(ChassisError::IllegalInput(
+                    "only `'static` lifetimes are supported".into(),
+                    lifetime.span(),
+                ))
+            }
         }
 
         if self.mutability.is_some() {
-            f.write_str("&mut ")?;
-        } else {
-            f.write_str("&")?;
+            f.write_str("mut ")?;
         }
         self.elem.conv_to_key_str(f)
     }
@@ -352,6 +401,12 @@ mod tests {
         assert_eq!("&mut String", ty.to_key_str().unwrap());
     }
 
+    #[test]
+    fn check_static_ref() {
+        let ty: syn::Type = syn::parse2(quote! { &'static String }).unwrap();
+        assert_eq!("&'static String", ty.to_key_str().unwrap());
+    }
+
     #[test]
     fn check_dyn_bound() {
         let ty: syn::Type = syn::parse2(quote! { dyn Trait1 + Trait2 }).unwrap();
@@ -369,4 +424,30 @@ mod tests {
         let ty: syn::Type = syn::parse2(quote! { impl Trait1 + Trait2 }).unwrap();
         assert_eq!("impl Trait1+Trait2", ty.to_key_str().unwrap());
     }
+
+    fn similarity(a: &str, b: &str) -> Option<usize> {
+        let a = StaticKey::try_new(&syn::parse_str(a).unwrap()).unwrap();
+        let b = StaticKey::try_new(&syn::parse_str(b).unwrap()).unwrap();
+        a.similarity(&b)
+    }
+
+    #[test]
+    fn check_similar_wrapper() {
+        assert!(similarity("Arc<Foo>", "Rc<Foo>").is_some());
+        assert!(similarity("Foo", "std::boxed::Box<Foo>").is_some());
+        assert!(similarity("&Foo", "Foo").is_some());
+    }
+
+    #[test]
+    fn check_similar_path() {
+        assert!(similarity("crate::a::Foo", "b::Foo").is_some());
+        assert!(similarity("Foo<u32>", "a::Foo<u32>").is_some());
+    }
+
+    #[test]
+    fn check_similar_typo() {
+        assert!(similarity("Printer", "Printr").is_some());
+        assert!(similarity("Printer", "Message").is_none());
+        assert!(similarity("Foo<u32>", "Foo<String>").is_none());
+    }
 }
@@ -0,0 +1,97 @@
+#![cfg_attr(nightly_diagnostics, feature(proc_macro_diagnostic, proc_macro_span))]
+
+//! Code generation of the [chassis](https://docs.rs/chassis) dependency injection framework.
+//!
+//! This crate is shared by the `#[integration]` attribute of `chassis` and the build script
+//! API of `chassis-build`. Use one of them instead of this crate.
+
+#[cfg(nightly_diagnostics)]
+extern crate proc_macro;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+
+use crate::codegen::{codegen_block, codegen_dyn_modules};
+use crate::errors::{codegen_errors, ChassisResult};
+use crate::parse::{parse_block, IntegrationAttrArgs};
+
+pub use crate::errors::ChassisError;
+
+mod cfg;
+mod codegen;
+mod container;
+mod diagnostic;
+mod errors;
+mod key;
+mod model;
+mod parse;
+mod syn_ext;
+mod utils;
+
+/// Expand the `#[integration(args)]` attribute on the module `input`.
+pub fn integration(args: TokenStream2, input: TokenStream2) -> TokenStream2 {
+    let args: IntegrationAttrArgs = match syn::parse2(args) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
+    let mod_block: syn::ItemMod = match syn::parse2(input) {
+        Ok(mod_block) => mod_block,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    match parse_integration(args, mod_block) {
+        Ok(tokens) => tokens,
+        Err(err) => codegen_errors(err),
+    }
+}
+
+/// Generate the component implementations for the modules and component traits in `items`.
+///
+/// Chassis attributes are removed from `items`, so they can be emitted next to the result.
+pub fn generate(items: &mut Vec<syn::Item>) -> Result<TokenStream2, ChassisError> {
+    let block = parse_block(items)?;
+    codegen_block(&block)
+}
+
+fn parse_integration(
+    args: IntegrationAttrArgs,
+    mut mod_block: syn::ItemMod,
+) -> ChassisResult<TokenStream2> {
+    let mut mod_impl = match &mut mod_block.content {
+        Some((_, items)) => items,
+        None => {
+            return Err(ChassisError::IllegalInput(
+                "Expected module implementation when using integration attribute".to_string(),
+                mod_block.span(),
+            ))
+        }
+    };
+
+    // Parse components and modules
+    let block = parse_block(&mut mod_impl)?;
+
+    // analyse and generate
+    let component_impls = codegen_block(&block)?;
+    let dyn_modules = if args.dyn_module {
+        codegen_dyn_modules(&block)?
+    } else {
+        TokenStream2::new()
+    };
+
+    // generate result
+    let mod_name = &mod_block.ident;
+    let mod_vis = &mod_block.vis;
+    Ok(quote! {
+        #mod_vis mod #mod_name {
+            #(#mod_impl)*
+
+            #component_impls
+
+            #dyn_modules
+        }
+    })
+}
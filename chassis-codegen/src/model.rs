@@ -0,0 +1,152 @@
+use proc_macro2::{Ident, Span};
+
+use crate::cfg::CfgExpr;
+use crate::key::StaticKey;
+
+/// place where injection happens
+///
+/// For example a factory signature
+#[derive(Clone)]
+pub struct InjectionPoint {
+    /// a name for the injectee
+    pub qualifier: String,
+
+    /// Dependencies from function signature
+    pub deps: Vec<Dependency>,
+}
+
+/// Dependency on key to be injected
+///
+/// Part of injection point
+#[derive(Clone)]
+pub struct Dependency {
+    /// Key for injection
+    pub key: StaticKey,
+
+    // Span of type in injection point
+    pub span: Span,
+
+    /// Type of parameter in injection point
+    pub ty: syn::Type,
+
+    /// index of parameter in injection point
+    pub parameter_index: u8,
+}
+
+/// Kind of binding used
+///
+/// Used for inspection and error messages. Analog to [Implementation].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum BindingType {
+    /// created by calling the provider function
+    Factory,
+    // Instance,
+    /// coerced from the only dependency, declared with `#[binds]`
+    Linked,
+    /// value of a const item marked with `#[provide]`
+    Constant,
+    /// reference to a static item marked with `#[provide]`
+    Static,
+    /// created with `Default::default()`, listed in `#[component(default_bindings = [...])]`
+    Default,
+}
+
+/// Implementation for binding
+#[derive(Clone)]
+pub struct Implementation {
+    pub binding_type: BindingType,
+    pub rty: syn::Type,
+    /// module of the provider function, `None` for const and static items
+    pub module: Option<Box<syn::Type>>,
+    pub func: Ident,
+    pub injection_point: InjectionPoint,
+    pub singleton: bool,
+
+    /// created at most once per call of a component method
+    pub reusable: bool,
+
+    /// function to call with the singleton before the component is dropped
+    pub dispose: Option<syn::Path>,
+}
+
+/// Bind a implementation to a key
+#[derive(Clone)]
+pub struct Binding {
+    pub key: StaticKey,
+    pub implementation: Implementation,
+
+    /// condition from `#[cfg(...)]` attributes of provider function and module
+    pub cfg: Option<CfgExpr>,
+}
+
+/// Group of bindings
+pub struct Module {
+    pub name: syn::TypePath,
+    pub bindings: Vec<Binding>,
+
+    /// condition from `#[cfg(...)]` attributes of the impl block
+    pub cfg: Option<CfgExpr>,
+
+    /// other modules whose bindings are used together with this module
+    pub includes: Vec<syn::Path>,
+}
+
+/// One injector specification entry.
+#[derive(Clone)]
+pub struct Request {
+    /// function name
+    pub name: syn::Ident,
+
+    /// type of request
+    pub ty: syn::Type,
+
+    /// key to resolve (normalized type)
+    pub key: StaticKey,
+
+    /// provider function explicitly linked with `#[provided_by(Module::function)]`
+    pub provider: Option<syn::Path>,
+}
+
+/// Closed collection of bindings and requests.
+///
+/// Components are implementing Injectors with bindings.
+pub struct ComponentTrait {
+    pub requests: Vec<Request>,
+    pub trait_name: syn::Ident,
+
+    /// type parameters of a generic component trait
+    pub generics: Vec<syn::Ident>,
+
+    /// other component traits this trait extends
+    pub supertraits: Vec<syn::Path>,
+
+    /// name of generated struct, `<Trait>Impl` if not given
+    pub impl_name: Option<syn::Ident>,
+
+    /// visibility of generated struct and its constructor, `pub` if not given
+    pub vis: Option<syn::Visibility>,
+
+    /// implement `Debug` for generated struct
+    pub derive_debug: bool,
+
+    /// type of injector to resolve keys at runtime that no module provides
+    pub fallback: Option<syn::Path>,
+
+    /// modules used by the component, all modules of the integration block if not given
+    pub modules: Option<Vec<syn::Path>>,
+
+    /// bindings created with `Default::default()` for keys no module provides
+    pub default_bindings: Vec<Binding>,
+
+    /// condition from `#[cfg(...)]` attributes of the trait
+    pub cfg: Option<CfgExpr>,
+}
+
+/// Definition block of components and modules
+pub struct Block {
+    pub modules: Vec<Module>,
+    pub components: Vec<ComponentTrait>,
+
+    /// bindings of const and static items, available to all components
+    pub constants: Vec<Binding>,
+}
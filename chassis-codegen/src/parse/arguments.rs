@@ -0,0 +1,176 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+/// attribute argument like `modules = [mymod::MyModule]`
+pub struct ComponentAttrArg {
+    pub name: syn::Ident,
+    pub value: ComponentAttrValue,
+}
+
+/// value of an attribute argument
+pub enum ComponentAttrValue {
+    /// list of paths like `[mymod::MyModule]`
+    List(Punctuated<syn::Path, Token![,]>),
+
+    /// single path like `dyn_chassis::Injector`
+    Path(syn::Path),
+
+    /// string like `"AppInjector"`
+    Str(syn::LitStr),
+
+    /// list of paths in parentheses like `derive(Debug)`
+    Parenthesized(Punctuated<syn::Path, Token![,]>),
+}
+
+/// attribute arguments like `#[component(modules = [mymod::MyModule], ...)]`
+pub struct ComponentAttrArgs {
+    pub args: Punctuated<ComponentAttrArg, Token![,]>,
+}
+
+impl Parse for ComponentAttrArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(ComponentAttrArgs {
+            args: input.parse_terminated(ComponentAttrArg::parse)?,
+        })
+    }
+}
+
+impl Parse for ComponentAttrArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let value =
+                ComponentAttrValue::Parenthesized(content.parse_terminated(syn::Path::parse)?);
+            return Ok(ComponentAttrArg { name, value });
+        }
+
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            ComponentAttrValue::List(content.parse_terminated(syn::Path::parse)?)
+        } else if input.peek(syn::LitStr) {
+            ComponentAttrValue::Str(input.parse()?)
+        } else {
+            ComponentAttrValue::Path(input.parse()?)
+        };
+        Ok(ComponentAttrArg { name, value })
+    }
+}
+
+impl ComponentAttrArg {
+    fn error(&self, expected: &str) -> syn::Error {
+        syn::Error::new(
+            self.name.span(),
+            format!("Expected argument like `{}{}`", self.name, expected),
+        )
+    }
+
+    /// Value of argument like `modules = [mymod::MyModule]`.
+    pub fn into_list(self) -> syn::Result<Vec<syn::Path>> {
+        match self.value {
+            ComponentAttrValue::List(list) => Ok(list.into_iter().collect()),
+            _ => Err(self.error(" = [...]")),
+        }
+    }
+
+    /// Value of argument like `fallback = dyn_chassis::Injector`.
+    pub fn into_path(self) -> syn::Result<syn::Path> {
+        match self.value {
+            ComponentAttrValue::Path(path) => Ok(path),
+            _ => Err(self.error(" = my::Type")),
+        }
+    }
+
+    /// Value of argument like `name = "AppInjector"`.
+    pub fn into_str(self) -> syn::Result<syn::LitStr> {
+        match self.value {
+            ComponentAttrValue::Str(lit) => Ok(lit),
+            _ => Err(self.error(" = \"...\"")),
+        }
+    }
+
+    /// Value of argument like `derive(Debug)`.
+    pub fn into_parenthesized(self) -> syn::Result<Vec<syn::Path>> {
+        match self.value {
+            ComponentAttrValue::Parenthesized(list) => Ok(list.into_iter().collect()),
+            _ => Err(self.error("(...)")),
+        }
+    }
+}
+
+/// singleton attribute arguments like `#[singleton(dispose = close_pool)]`
+pub struct SingletonAttrArgs {
+    pub dispose: Option<syn::Path>,
+}
+
+impl Parse for SingletonAttrArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(SingletonAttrArgs { dispose: None });
+        }
+
+        let content;
+        parenthesized!(content in input);
+
+        let mut dispose = None;
+        for arg in content.parse_terminated::<_, Token![,]>(SingletonAttrArg::parse)? {
+            if arg.name != "dispose" {
+                return Err(syn::Error::new(
+                    arg.name.span(),
+                    format!("Unknown singleton argument `{}`", arg.name),
+                ));
+            }
+            if dispose.is_some() {
+                return Err(syn::Error::new(
+                    arg.name.span(),
+                    "Duplicate singleton argument `dispose`",
+                ));
+            }
+            dispose = Some(arg.value);
+        }
+
+        Ok(SingletonAttrArgs { dispose })
+    }
+}
+
+/// singleton attribute argument like `dispose = close_pool`
+struct SingletonAttrArg {
+    name: syn::Ident,
+    value: syn::Path,
+}
+
+impl Parse for SingletonAttrArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(SingletonAttrArg { name, value })
+    }
+}
+
+/// arguments of the integration attribute like `#[chassis::integration(dyn_module)]`
+#[derive(Default)]
+pub struct IntegrationAttrArgs {
+    /// also implement `dyn_chassis::Module` for every module
+    pub dyn_module: bool,
+}
+
+impl Parse for IntegrationAttrArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = IntegrationAttrArgs::default();
+        for arg in input.parse_terminated::<syn::Ident, Token![,]>(syn::Ident::parse)? {
+            if arg == "dyn_module" {
+                args.dyn_module = true;
+            } else {
+                return Err(syn::Error::new(
+                    arg.span(),
+                    format!("Unknown integration argument `{}`", arg),
+                ));
+            }
+        }
+        Ok(args)
+    }
+}
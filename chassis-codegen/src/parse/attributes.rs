@@ -7,6 +7,8 @@ use crate::utils::to_tokens;
 pub enum InjectAttrType {
     Annotation,
     Singleton,
+    Reusable,
+    Binds,
 }
 
 pub struct InjectAttr {
@@ -16,7 +18,11 @@ pub struct InjectAttr {
 
 pub fn is_chassis_attr(attr: &Attribute) -> bool {
     let segs = &attr.path.segments;
-    segs.len() == 1 && (segs[0].ident == "annotation" || segs[0].ident == "singleton")
+    segs.len() == 1
+        && (segs[0].ident == "annotation"
+            || segs[0].ident == "singleton"
+            || segs[0].ident == "reusable"
+            || segs[0].ident == "binds")
 }
 
 pub fn parse_attr(attr: Attribute) -> InjectAttr {
@@ -39,6 +45,8 @@ pub fn parse_attr(attr: Attribute) -> InjectAttr {
     let ty = match &parts[0].ident.to_string() as &str {
         "annotation" => InjectAttrType::Annotation,
         "singleton" => InjectAttrType::Singleton,
+        "reusable" => InjectAttrType::Reusable,
+        "binds" => InjectAttrType::Binds,
         _ => panic!("Unknown chassis attribute: {}", to_tokens(&attr)),
     };
 
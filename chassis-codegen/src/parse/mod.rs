@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+
+use syn::spanned::Spanned;
+use syn::{
+    GenericParam, ImplItem, ImplItemMethod, Item, ItemImpl, ItemTrait, ReturnType, Stmt, TraitItem,
+    Type, TypeParamBound,
+};
+
+use crate::cfg::CfgExpr;
+use crate::errors::{ChassisError, ChassisResult};
+use crate::key::StaticKey;
+use crate::model::{
+    Binding, BindingType, Block, ComponentTrait, Dependency, Implementation, InjectionPoint,
+    Module, Request,
+};
+use crate::parse::arguments::{ComponentAttrArg, ComponentAttrArgs, SingletonAttrArgs};
+use crate::parse::attributes::InjectAttrType;
+use crate::parse::signature::process_sig;
+
+mod arguments;
+mod attributes;
+mod signature;
+
+pub use self::arguments::IntegrationAttrArgs;
+pub use self::signature::{parse_inject_type, WrapperType};
+
+fn drain_where<T: Clone, F: Fn(&T) -> bool>(v: &mut Vec<T>, f: F) -> Vec<T> {
+    // TODO: use Vec::drain_filter when stabilised
+    let res: Vec<T> = v.iter().filter(|x| f(x)).cloned().collect();
+    v.retain(|x| !f(x));
+    res
+}
+
+fn syn_error(err: syn::Error) -> ChassisError {
+    ChassisError::IllegalInput(err.to_string(), err.span())
+}
+
+pub fn eq_attr_name(attr: &syn::Attribute, seg0: &str) -> bool {
+    let segs = &attr.path.segments;
+    segs.len() == 1 && segs[0].ident == seg0
+}
+
+pub fn parse_block(mod_impl: &mut Vec<Item>) -> ChassisResult<Block> {
+    let mut components: Vec<ComponentTrait> = vec![];
+    let mut modules: Vec<Module> = vec![];
+    let mut constants: Vec<Binding> = vec![];
+    for item in mod_impl {
+        match item {
+            // module definition
+            Item::Impl(impl_block) => {
+                // TODO: implicit?
+                let attrs = drain_where(&mut impl_block.attrs, |attr| eq_attr_name(attr, "module"));
+                if attrs.len() > 1 {
+                    // TODO: hint for every attr
+                    return Err(ChassisError::IllegalInput(
+                        "More than one chassis attribute found".to_string(),
+                        attrs[0].span(),
+                    ));
+                } else {
+                    modules.push(parse_module(attrs.into_iter().next(), impl_block)?);
+                }
+            }
+
+            // component definition
+            Item::Trait(trait_block) => {
+                // TODO: implicit?
+                let attrs = drain_where(&mut trait_block.attrs, |attr| {
+                    eq_attr_name(attr, "component")
+                });
+                if attrs.len() > 1 {
+                    // TODO: hint for every attr
+                    return Err(ChassisError::IllegalInput(
+                        "More than one chassis attribute found".to_string(),
+                        attrs[0].span(),
+                    ));
+                } else {
+                    components.push(parse_component(attrs.into_iter().next(), trait_block)?);
+                }
+            }
+
+            // constant definition
+            Item::Const(const_item) => {
+                let attrs =
+                    drain_where(&mut const_item.attrs, |attr| eq_attr_name(attr, "provide"));
+                if !attrs.is_empty() {
+                    constants.push(parse_constant(
+                        BindingType::Constant,
+                        &const_item.attrs,
+                        &const_item.ident,
+                        (*const_item.ty).clone(),
+                    )?);
+                }
+            }
+            Item::Static(static_item) => {
+                let attrs =
+                    drain_where(&mut static_item.attrs, |attr| eq_attr_name(attr, "provide"));
+                if !attrs.is_empty() {
+                    if let Some(mutability) = &static_item.mutability {
+                        return Err(ChassisError::IllegalInput(
+                            "Mutable statics can not be provided".to_string(),
+                            mutability.span(),
+                        ));
+                    }
+
+                    let ty = &static_item.ty;
+                    constants.push(parse_constant(
+                        BindingType::Static,
+                        &static_item.attrs,
+                        &static_item.ident,
+                        parse_quote! { &'static #ty },
+                    )?);
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    Ok(Block {
+        modules,
+        components,
+        constants,
+    })
+}
+
+/// Binding for const or static item `ident` which is injected as `rty`.
+fn parse_constant(
+    binding_type: BindingType,
+    attrs: &[syn::Attribute],
+    ident: &syn::Ident,
+    rty: Type,
+) -> ChassisResult<Binding> {
+    Ok(Binding {
+        key: StaticKey::try_new(&rty)?,
+        cfg: CfgExpr::from_attrs(attrs)?,
+        implementation: Implementation {
+            binding_type,
+            rty,
+            module: None,
+            func: ident.clone(),
+            injection_point: InjectionPoint {
+                qualifier: ident.to_string(),
+                deps: vec![],
+            },
+            singleton: false,
+            reusable: false,
+            dispose: None,
+        },
+    })
+}
+
+fn parse_signature(sig: &syn::Signature) -> ChassisResult<Request> {
+    let ty = match &sig.output {
+        ReturnType::Default => {
+            return Err(ChassisError::IllegalInput(
+                "Return type is required".to_string(),
+                sig.span(),
+            ));
+        }
+        ReturnType::Type(_, ty) => ty.clone(),
+    };
+
+    // TODO: len(args) == 0
+    Ok(Request {
+        key: StaticKey::try_new(&ty)?,
+        ty: *ty,
+        name: sig.ident.clone(),
+        provider: None,
+    })
+}
+
+fn parse_provided_by(attrs: &mut Vec<syn::Attribute>) -> ChassisResult<Option<syn::Path>> {
+    let attrs = drain_where(attrs, |attr| eq_attr_name(attr, "provided_by"));
+    if attrs.len() > 1 {
+        return Err(ChassisError::IllegalInput(
+            "More than one provided_by attribute found".to_string(),
+            attrs[1].span(),
+        ));
+    }
+
+    attrs
+        .into_iter()
+        .next()
+        .map(|attr| {
+            attr.parse_args::<syn::Path>()
+                .map_err(|err| ChassisError::IllegalInput(err.to_string(), err.span()))
+        })
+        .transpose()
+}
+
+/// Parse arguments like `modules = [MyModule]` of a chassis attribute.
+///
+/// Only arguments in `names` are allowed.
+fn parse_attr_args(
+    attr: Option<&syn::Attribute>,
+    names: &[&str],
+) -> ChassisResult<HashMap<String, ComponentAttrArg>> {
+    let mut values = HashMap::new();
+    let attr = match attr {
+        Some(attr) if !attr.tokens.is_empty() => attr,
+        _ => return Ok(values),
+    };
+
+    let args: ComponentAttrArgs = attr
+        .parse_args()
+        .map_err(|err| ChassisError::IllegalInput(err.to_string(), err.span()))?;
+    for arg in args.args {
+        let name = arg.name.to_string();
+        if !names.contains(&name.as_str()) {
+            return Err(ChassisError::IllegalInput(
+                format!("Unknown argument `{}`", name),
+                arg.name.span(),
+            ));
+        }
+        if values.contains_key(&name) {
+            return Err(ChassisError::IllegalInput(
+                format!("Duplicate argument `{}`", name),
+                arg.name.span(),
+            ));
+        }
+        values.insert(name, arg);
+    }
+    Ok(values)
+}
+
+pub fn parse_component(
+    attr: Option<syn::Attribute>,
+    trait_block: &mut ItemTrait,
+) -> ChassisResult<ComponentTrait> {
+    let mut args = parse_attr_args(
+        attr.as_ref(),
+        &[
+            "modules",
+            "fallback",
+            "name",
+            "vis",
+            "derive",
+            "default_bindings",
+        ],
+    )?;
+    let impl_name = args
+        .remove("name")
+        .map(|arg| arg.into_str()?.parse::<syn::Ident>())
+        .transpose()
+        .map_err(syn_error)?;
+    let vis = args
+        .remove("vis")
+        .map(|arg| arg.into_str()?.parse::<syn::Visibility>())
+        .transpose()
+        .map_err(syn_error)?;
+    let derives = args
+        .remove("derive")
+        .map(ComponentAttrArg::into_parenthesized)
+        .transpose()
+        .map_err(syn_error)?
+        .unwrap_or_default();
+    let default_bindings = args
+        .remove("default_bindings")
+        .map(ComponentAttrArg::into_list)
+        .transpose()
+        .map_err(syn_error)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|path| {
+            let ident = syn::Ident::new("default", path.span());
+            let rty = Type::Path(syn::TypePath { qself: None, path });
+            parse_constant(BindingType::Default, &[], &ident, rty)
+        })
+        .collect::<ChassisResult<Vec<Binding>>>()?;
+    if let Some(derive) = derives.iter().find(|derive| !derive.is_ident("Debug")) {
+        return Err(ChassisError::IllegalInput(
+            "Only `Debug` can be derived for components".to_string(),
+            derive.span(),
+        ));
+    }
+
+    // TODO: check for unsafe / auto
+    let generics = parse_component_generics(&trait_block.generics)?;
+    let supertraits = trait_block
+        .supertraits
+        .iter()
+        .map(|bound| match bound {
+            TypeParamBound::Trait(bound) if bound.lifetimes.is_none() => Ok(bound.path.clone()),
+            _ => Err(ChassisError::IllegalInput(
+                "Only component traits are allowed as supertraits".to_string(),
+                bound.span(),
+            )),
+        })
+        .collect::<ChassisResult<Vec<syn::Path>>>()?;
+
+    let mut requests: Vec<Request> = vec![];
+    for item in trait_block.items.iter_mut() {
+        match item {
+            TraitItem::Method(method) => {
+                if let Some(default) = &method.default {
+                    return Err(ChassisError::IllegalInput(
+                        "Default implementation not allowed".to_string(),
+                        default.span(),
+                    ));
+                }
+
+                // TODO: Check for &self
+                let mut request = parse_signature(&method.sig)?;
+                request.provider = parse_provided_by(&mut method.attrs)?;
+                requests.push(request)
+            }
+            TraitItem::Type(type_item) => {
+                return Err(ChassisError::IllegalInput(
+                    "Associated type not allowed in component".to_string(),
+                    type_item.span(),
+                ))
+            }
+            _ => (),
+        }
+    }
+
+    Ok(ComponentTrait {
+        requests,
+        trait_name: trait_block.ident.clone(),
+        generics,
+        supertraits,
+        modules: args
+            .remove("modules")
+            .map(ComponentAttrArg::into_list)
+            .transpose()
+            .map_err(syn_error)?,
+        fallback: args
+            .remove("fallback")
+            .map(ComponentAttrArg::into_path)
+            .transpose()
+            .map_err(syn_error)?,
+        impl_name,
+        vis,
+        derive_debug: !derives.is_empty(),
+        default_bindings,
+        cfg: CfgExpr::from_attrs(&trait_block.attrs)?,
+    })
+}
+
+fn parse_component_generics(generics: &syn::Generics) -> ChassisResult<Vec<syn::Ident>> {
+    if let Some(where_clause) = &generics.where_clause {
+        return Err(ChassisError::IllegalInput(
+            "Where clauses are not supported in components".to_string(),
+            where_clause.span(),
+        ));
+    }
+
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(ty) => Ok(ty.ident.clone()),
+            GenericParam::Lifetime(_) => Err(ChassisError::IllegalInput(
+                "Lifetime parameters are not supported in components".to_string(),
+                param.span(),
+            )),
+            GenericParam::Const(_) => Err(ChassisError::IllegalInput(
+                "Const parameters are not supported in components".to_string(),
+                param.span(),
+            )),
+        })
+        .collect()
+}
+
+pub fn parse_module(
+    attr: Option<syn::Attribute>,
+    impl_block: &mut ItemImpl,
+) -> ChassisResult<Module> {
+    let mut args = parse_attr_args(attr.as_ref(), &["includes"])?;
+
+    // TODO: check for generics / lifetimes / unsafe / auto / supertraits
+
+    let module_id = impl_block.self_ty.clone();
+    let module_cfg = CfgExpr::from_attrs(&impl_block.attrs)?;
+    let bindings: ChassisResult<Vec<_>> = impl_block
+        .items
+        .iter_mut()
+        .map(|item| parse_module_fn(module_id.clone(), module_cfg.as_ref(), item))
+        .collect();
+
+    // `#[binds]` declarations are not valid Rust
+    impl_block.items.retain(|item| match item {
+        ImplItem::Method(method) => !is_declaration(method),
+        _ => true,
+    });
+
+    Ok(Module {
+        name: match *impl_block.self_ty.clone() {
+            Type::Path(path) if path.qself.is_none() => path,
+            self_ty => {
+                return Err(ChassisError::IllegalInput(
+                    "Expected simple type in static_module impl type".to_string(),
+                    self_ty.span(),
+                ))
+            }
+        },
+        bindings: bindings?,
+        cfg: module_cfg,
+        includes: args
+            .remove("includes")
+            .map(ComponentAttrArg::into_list)
+            .transpose()
+            .map_err(syn_error)?
+            .unwrap_or_default(),
+    })
+}
+
+/// Checks for function without body like `fn printer(p: Rc<StdoutPrinter>) -> Rc<dyn Printer>;`
+fn is_declaration(method: &ImplItemMethod) -> bool {
+    match &method.block.stmts[..] {
+        [Stmt::Item(Item::Verbatim(tokens))] => tokens.to_string() == ";",
+        _ => false,
+    }
+}
+
+fn parse_module_fn(
+    module_id: Box<Type>,
+    module_cfg: Option<&CfgExpr>,
+    item: &mut ImplItem,
+) -> ChassisResult<Binding> {
+    match item {
+        ImplItem::Method(method) => {
+            let declaration = is_declaration(method);
+            let cfg = CfgExpr::from_attrs(&method.attrs)?;
+            let inject_fn = process_sig(method);
+            let mut binding = Binding {
+                cfg: CfgExpr::all(module_cfg.cloned().into_iter().chain(cfg)),
+                key: StaticKey::try_new(&inject_fn.output.outer_ty)?, // TODO: inner type must be used
+                implementation: Implementation {
+                    binding_type: BindingType::Factory,
+                    singleton: false,
+                    reusable: false,
+                    dispose: None,
+                    rty: inject_fn.output.outer_ty.clone(),
+                    module: Some(module_id),
+                    func: inject_fn.name.clone(),
+                    injection_point: InjectionPoint {
+                        qualifier: inject_fn.name.to_string(),
+                        deps: inject_fn
+                            .inputs
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, input)| {
+                                Ok(Dependency {
+                                    parameter_index: i as u8,
+                                    span: input.ty.outer_ty.span(),
+                                    ty: input.ty.outer_ty.clone(),
+                                    key: StaticKey::try_new(&input.ty.outer_ty)?, // TODO: attr, inner type
+                                })
+                            })
+                            .collect::<ChassisResult<Vec<Dependency>>>()?,
+                    },
+                },
+            };
+
+            for attr in &inject_fn.attrs {
+                match attr.ty {
+                    InjectAttrType::Annotation => {} // TODO
+                    InjectAttrType::Singleton => {
+                        let args: SingletonAttrArgs =
+                            syn::parse2(attr.tokens.clone()).map_err(|err| {
+                                ChassisError::IllegalInput(err.to_string(), err.span())
+                            })?;
+                        binding.implementation.singleton = true;
+                        binding.implementation.dispose = args.dispose;
+                    }
+                    InjectAttrType::Reusable => binding.implementation.reusable = true,
+                    InjectAttrType::Binds => {
+                        binding.implementation.binding_type = BindingType::Linked
+                    }
+                }
+            }
+
+            if binding.implementation.singleton && binding.implementation.reusable {
+                return Err(ChassisError::IllegalInput(
+                    "Provider function can not be singleton and reusable".to_string(),
+                    method.sig.ident.span(),
+                ));
+            }
+
+            match (binding.implementation.binding_type, declaration) {
+                (BindingType::Factory, true) => {
+                    return Err(ChassisError::IllegalInput(
+                        "Provider function without body has to be marked with `#[binds]`"
+                            .to_string(),
+                        method.sig.span(),
+                    ))
+                }
+                (BindingType::Linked, false) => {
+                    return Err(ChassisError::IllegalInput(
+                        "Binds declaration must not have a body".to_string(),
+                        method.block.span(),
+                    ))
+                }
+                (BindingType::Linked, true)
+                    if binding.implementation.injection_point.deps.len() != 1 =>
+                {
+                    return Err(ChassisError::IllegalInput(
+                        "Binds declaration must have exactly one parameter".to_string(),
+                        method.sig.inputs.span(),
+                    ))
+                }
+                _ => {}
+            }
+
+            Ok(binding)
+        }
+        _ => Err(ChassisError::IllegalInput(
+            "Unexpected item in chassis module definition".to_string(),
+            item.span(),
+        )),
+    }
+}
@@ -120,7 +120,8 @@ fn find_wrapper_type(ty: &Type) -> Option<InjectType> {
     }
 }
 
-fn parse_inject_type(ty: &Type) -> InjectType {
+/// Split smart pointer from type like `Arc<T>`.
+pub fn parse_inject_type(ty: &Type) -> InjectType {
     if let Some(inject_ptr) = find_wrapper_type(ty) {
         inject_ptr
     } else {
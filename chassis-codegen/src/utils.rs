@@ -0,0 +1,24 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+
+pub fn to_tokens(x: &impl ToTokens) -> TokenStream2 {
+    let mut tokens = TokenStream2::new();
+    x.to_tokens(&mut tokens);
+    tokens
+}
+
+/// Levenshtein distance between `a` and `b` counted in chars.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            prev = row[j + 1];
+            row[j + 1] = cost.min(prev + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
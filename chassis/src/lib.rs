@@ -1,5 +1,4 @@
 #![doc(html_root_url = "https://docs.rs/chassis/0.2.0")]
-#![cfg_attr(nightly_diagnostics, feature(proc_macro_diagnostic, proc_macro_span))]
 
 //! Compile-time dependency injector.
 //!
@@ -181,6 +180,11 @@
 //! }
 //!
 //! impl DemoComponent for DemoComponentImpl {
+//!     /// Resolution plan:
+//!     ///
+//!     /// * `Greeter` from `DemoModule::provide_greeter`
+//!     ///   * `Message` from `DemoModule::provide_message`
+//!     ///   * `Printer` from `DemoModule::provide_printer`
 //!     fn resolve_greeter(&self) -> Greeter {
 //!         DemoModule::provide_greeter(
 //!             DemoModule::provide_message(),
@@ -189,15 +193,284 @@
 //! }
 //! ```
 //!
+//! Every generated component method documents its resolution plan, so `cargo doc` and IDE
+//! hovers show which providers are called and which dependencies come from singletons.
+//!
 //! [cargo-expand]: https://crates.io/crates/cargo-expand
 //!
+//! ## Generated component struct
+//!
+//! The component struct is named `<Trait>Impl` and is public by default. Use the `name` and
+//! `vis` arguments of the `component` attribute to change the name and the visibility of the
+//! struct and its constructor. `derive(Debug)` implements [`Debug`] listing the types of the
+//! singletons of the component, the singletons themselves do not have to implement [`Debug`].
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   pub struct Config;
+//! #   pub struct Module;
+//! #   impl Module { pub fn provide_config() -> Config { Config } }
+//! #[component(name = "AppInjector", vis = "pub(crate)", derive(Debug))]
+//! pub trait AppComponent {
+//!     fn config(&self) -> Config;
+//! }
+//! # }
+//! ```
+//!
+//! ## Composing components
+//!
+//! A component trait can extend other component traits of the same integration module, which
+//! are named without a path. The generated implementation then also implements all
+//! supertraits. Generic component traits are not implemented by themselves, but have to be
+//! used as supertraits with concrete type arguments.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   pub struct Config;
+//! #   pub struct Repository<T>(T);
+//! #   pub struct User;
+//! #   pub struct Module;
+//! #   impl Module {
+//! #       pub fn provide_config() -> Config { Config }
+//! #       pub fn provide_users() -> Repository<User> { Repository(User) }
+//! #   }
+//! pub trait CoreComponent {
+//!     fn config(&self) -> Config;
+//! }
+//!
+//! pub trait RepositoryComponent<T> {
+//!     fn repository(&self) -> Repository<T>;
+//! }
+//!
+//! /// `AppComponentImpl` implements all three traits
+//! pub trait AppComponent: CoreComponent + RepositoryComponent<User> {}
+//! # }
+//! ```
+//!
+//! ## Composing modules
+//!
+//! A module can include other modules with `#[module(includes = [OtherModule])]`. A component
+//! can select its modules with `#[component(modules = [MyModule])]` and then only gets the
+//! bindings of these modules and all modules included by them. Without a module list a
+//! component uses all modules of the integration module.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   pub struct Logger;
+//! #   pub struct Metrics;
+//! #   pub struct LoggingModule;
+//! #   pub struct MetricsModule;
+//! #   pub struct WebModule;
+//! #   impl LoggingModule { pub fn provide_logger() -> Logger { Logger } }
+//! #   impl MetricsModule { pub fn provide_metrics() -> Metrics { Metrics } }
+//! #[module(includes = [LoggingModule, MetricsModule])]
+//! impl WebModule {}
+//!
+//! #[component(modules = [WebModule])]
+//! pub trait WebComponent {
+//!     fn logger(&self) -> Logger;
+//! }
+//! # }
+//! ```
+//!
+//! ## Binding interfaces
+//!
+//! A provider function that only converts its argument to an interface type can be declared
+//! without body and marked with `#[binds]`. The generated code does the unsizing coercion. When
+//! the implementation is a singleton, the singleton is reused.
+//! ```rust,no_run
+//! # pub trait Printer {}
+//! # pub struct StdoutPrinter;
+//! # impl Printer for StdoutPrinter {}
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   use super::*;
+//! #   use std::rc::Rc;
+//! #   pub struct Module;
+//! impl Module {
+//!     #[singleton]
+//!     pub fn provide_stdout_printer() -> Rc<StdoutPrinter> {
+//!         Rc::new(StdoutPrinter)
+//!     }
+//!
+//!     #[binds]
+//!     fn printer(p: Rc<StdoutPrinter>) -> Rc<dyn Printer>;
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+//!
+//! ## Hiding concrete types
+//!
+//! Component methods can return `impl Trait` to hide the concrete types of a component's
+//! public API. Link the request to the provider function that creates the concrete type with
+//! `#[provided_by(Module::function)]`. The compiler checks that the provided type implements
+//! the trait. A request without link is only resolved by a provider which itself returns the
+//! same `impl Trait` type.
+//! ```rust,no_run
+//! # pub trait Greeter {}
+//! # pub struct EnglishGreeter;
+//! # impl Greeter for EnglishGreeter {}
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   use super::*;
+//! #   pub struct Module;
+//! #   impl Module {
+//! #       pub fn provide_english_greeter() -> EnglishGreeter { EnglishGreeter }
+//! #   }
+//! pub trait GreeterComponent {
+//!     #[provided_by(Module::provide_english_greeter)]
+//!     fn greeter(&self) -> impl Greeter;
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+//!
+//! ## Constants
+//!
+//! `const` and `static` items of the integration module marked with `#[provide]` are bindings
+//! available to all components. A constant is bound by its type, a static by a `&'static`
+//! reference to its type.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   use std::time::Duration;
+//! #   pub struct Config;
+//! #[provide]
+//! const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+//!
+//! #[provide]
+//! static CONFIG: Config = Config;
+//!
+//! pub trait ClientComponent {
+//!     fn timeout(&self) -> Duration;
+//!     fn config(&self) -> &'static Config;
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+//! Constants are not registered in [runtime modules](#runtime-modules).
+//!
+//! ## Default bindings
+//!
+//! Types implementing [`Default`] can be listed in `#[component(default_bindings = [...])]`
+//! instead of writing a provider function for every one of them. They are created with
+//! `Default::default()` whenever no module of the component provides them.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   #[derive(Default)]
+//! #   pub struct Config;
+//! #   #[derive(Default)]
+//! #   pub struct Metrics;
+//! #[component(default_bindings = [Config, Metrics])]
+//! pub trait ServerComponent {
+//!     fn config(&self) -> Config;
+//!     fn metrics(&self) -> Metrics;
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+//!
+//! ## Conditional compilation
+//!
+//! Provider functions, module `impl` blocks and component traits can be gated with `#[cfg(...)]`.
+//! The dependency graph is checked for every combination of the used cfg predicates and the
+//! generated code is gated accordingly, so a provider may be replaced by another one for tests
+//! or for a platform.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   pub struct Clock;
+//! #   pub struct Module;
+//! impl Module {
+//!     #[cfg(not(test))]
+//!     pub fn provide_clock() -> Clock {
+//!         Clock
+//!     }
+//!
+//!     #[cfg(test)]
+//!     pub fn provide_clock() -> Clock {
+//!         Clock
+//!     }
+//! }
+//! # }
+//! ```
+//! At most 8 distinct predicates are supported per integration module. Errors in combinations
+//! which are never compiled (like `all(unix, windows)`) are only reported if the combination is
+//! actually enabled.
+//!
+//! ## Runtime modules
+//!
+//! With `#[chassis::integration(dyn_module)]` every module also implements
+//! `dyn_chassis::Module`, so it can be installed in a runtime `dyn_chassis::Injector`. A
+//! provider returning `Arc<T>` is bound to `T`, other return types are bound as they are.
+//! Dependencies of type `Arc<T>` are resolved from the binding of `T`, all other dependencies
+//! are cloned from their binding and have to implement [`Clone`].
+//! ```rust,no_run
+//! #[chassis::integration(dyn_module)]
+//! mod integration {
+//!     use std::sync::Arc;
+//!
+//!     pub struct Config;
+//!     pub struct Module;
+//!
+//!     impl Module {
+//!         #[singleton]
+//!         pub fn provide_config() -> Arc<Config> {
+//!             Arc::new(Config)
+//!         }
+//!     }
+//! }
+//!
+//! let injector = dyn_chassis::Injector::from_module(integration::Module).unwrap();
+//! let config = injector.resolve_type::<integration::Config>();
+//! ```
+//! Singletons are created once per installed module. Reusable bindings are unscoped, dispose
+//! hooks and module includes are ignored, so included modules have to be installed
+//! separately. Providers with `impl Trait` types can not be registered.
+//!
+//! ## Runtime fallback
+//!
+//! Keys no module of a component provides are a compile error. With
+//! `#[component(fallback = dyn_chassis::Injector)]` these keys are resolved at runtime from an
+//! injector passed to `ComponentImpl::new` instead, for example to use implementations of
+//! plugins which are only known at runtime. They are resolved like dependencies of
+//! [runtime modules](#runtime-modules). The injector is not checked for the keys: if it has no
+//! binding for a key, the component method panics with `Failed to resolve` and the key, or
+//! `ComponentImpl::new` does if a singleton depends on the key.
+//! ```rust,no_run
+//! # use std::sync::Arc;
+//! # pub trait Plugin {}
+//! #[chassis::integration]
+//! mod integration {
+//! #   use super::*;
+//!     #[component(fallback = dyn_chassis::Injector)]
+//!     pub trait PluginComponent {
+//!         fn plugin(&self) -> Arc<dyn Plugin>;
+//!     }
+//! }
+//!
+//! # fn load_plugins() -> dyn_chassis::Injector { unimplemented!() }
+//! # fn main() {
+//! let component = integration::PluginComponentImpl::new(load_plugins());
+//! # }
+//! ```
+//!
+//! ## Build scripts
+//!
+//! The modules and components can also be described in a TOML file, which is compiled by the
+//! build script with [`chassis-build`](https://docs.rs/chassis-build). The same checks are
+//! done, but the providers do not have to be defined in one module and the generated code is
+//! written to `OUT_DIR`.
+//!
 //! ## Limitations
 //! * Dependencies are looked up through the syntax token
 //!     * `Rc<Dep>` and `Rc< Dep >` are the same
 //!     * but `Rc<Dep>` and `Rc<crate::Dep>` never
 //!     * Also types aliases with `type` result in different type keys
-//! * Currently lifetimes in the types are not supported (also `'static`)
-//! * Currently generics are not handeled correctly
+//! * Currently lifetimes in the types are not supported (except `'static`)
+//! * Currently generics are not handeled correctly (except for generic component traits)
 //! * Currently only the first error is show at compile time
 //! * Currently modules can not have `&self`-methods, so inner data is useless
 //! * Currently it is not possible to request a reference to a registered non-reference type
@@ -230,86 +503,68 @@
 //! # }
 //! ```
 //!
+//! ### Teardown
+//!
+//! Singletons are dropped in reverse dependency order when the component is dropped or
+//! `ComponentImpl::close` is called, so a singleton is always dropped before its dependencies.
+//! Use the `dispose` argument to call a function with the singleton before any singleton is
+//! dropped, for example to flush writers or close pools. The function gets a mutable reference
+//! to the singleton.
+//! ```rust,no_run
+//! # pub struct Pool;
+//! # impl Pool { fn close(&self) {} }
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   use std::rc::Rc;
+//! #   use super::Pool;
+//! #   struct Module;
+//! pub fn close_pool(pool: &Rc<Pool>) {
+//!     pool.close();
+//! }
+//!
+//! impl Module {
+//!     #[singleton(dispose = close_pool)]
+//!     pub fn provide_pool() -> Rc<Pool> {
+//!         Rc::new(Pool)
+//!     }
+//! }
+//! # }
+//! # fn main() {}
+//! ```
+//!
+//! ## Reusable instances
+//!
+//! A provider marked with `#[reusable]` is called at most once per call of a component method.
+//! All dependents in the dependency tree of that call share the instance, so diamond-shaped
+//! dependencies do not create duplicate objects. Like singletons the type has to implement
+//! [`Clone`]. Dependencies of singletons are created once per singleton instead, because
+//! singletons are created with the component.
+//! ```rust,no_run
+//! # #[chassis::integration]
+//! # mod integration {
+//! #   use std::rc::Rc;
+//! #   pub struct Connection;
+//! #   struct Module;
+//! impl Module {
+//!     #[reusable]
+//!     pub fn provide_connection() -> Rc<Connection> {
+//!         Rc::new(Connection)
+//!     }
+//! }
+//! # }
+//! ```
+//!
 //! [`Clone`]: std::clone::Clone
 //! [`Copy`]: std::marker::Copy
+//! [`Debug`]: std::fmt::Debug
+//! [`Default`]: std::default::Default
 //! [`Rc`]: std::rc::Rc
 //! [`Arc`]: std::sync::Arc
 
-#[macro_use]
-extern crate quote;
-#[macro_use]
-extern crate syn;
-
 use proc_macro::TokenStream;
 
-use proc_macro2::TokenStream as TokenStream2;
-use syn::spanned::Spanned;
-
-use crate::codegen::codegen_component_impl;
-use crate::container::IocContainer;
-use crate::errors::{codegen_errors, ChassisError, ChassisResult};
-use crate::parse::parse_block;
-
-mod codegen;
-mod container;
-mod diagnostic;
-mod errors;
-mod key;
-mod model;
-mod parse;
-mod syn_ext;
-mod utils;
-
 /// Attribute for modules
 #[proc_macro_attribute]
-pub fn integration(_args: TokenStream, input: TokenStream) -> TokenStream {
-    let mod_block: syn::ItemMod = parse_macro_input!(input);
-
-    match parse_integration(_args, mod_block) {
-        Ok(tokens) => tokens.into(),
-        Err(err) => codegen_errors(err).into(),
-    }
-}
-
-fn parse_integration(
-    _args: TokenStream,
-    mut mod_block: syn::ItemMod,
-) -> ChassisResult<TokenStream2> {
-    let mut mod_impl = match &mut mod_block.content {
-        Some((_, items)) => items,
-        None => {
-            return Err(ChassisError::IllegalInput(
-                "Expected module implementation when using integration attribute".to_string(),
-                mod_block.span(),
-            ))
-        }
-    };
-
-    // Parse components and modules
-    let block = parse_block(&mut mod_impl)?;
-
-    // analyse
-    let modules = block.modules;
-    let mut container = IocContainer::new();
-    for module in modules {
-        container.add_module(module)?;
-    }
-
-    // generate
-    let component_impls = block
-        .components
-        .into_iter()
-        .map(|comp| codegen_component_impl(comp, &container))
-        .collect::<ChassisResult<Vec<TokenStream2>>>()?;
-
-    // generate result
-    let mod_name = &mod_block.ident;
-    let mod_vis = &mod_block.vis;
-    Ok(quote! {
-        #mod_vis mod #mod_name {
-            #(#mod_impl)*
-
-            #(#component_impls)*
-        }
-    })
+pub fn integration(args: TokenStream, input: TokenStream) -> TokenStream {
+    chassis_codegen::integration(args.into(), input.into()).into()
 }
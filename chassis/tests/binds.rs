@@ -0,0 +1,64 @@
+use chassis::integration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static INSTANCES: AtomicUsize = AtomicUsize::new(0);
+
+pub trait Printer {
+    fn print(&self, input: &str) -> String;
+}
+
+pub struct StdoutPrinter;
+
+impl StdoutPrinter {
+    pub fn create() -> Self {
+        INSTANCES.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Printer for StdoutPrinter {
+    fn print(&self, input: &str) -> String {
+        format!("> {}", input)
+    }
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+    use std::rc::Rc;
+
+    pub struct PrinterModule;
+
+    impl PrinterModule {
+        #[singleton]
+        pub fn provide_stdout_printer() -> Rc<StdoutPrinter> {
+            Rc::new(StdoutPrinter::create())
+        }
+
+        #[binds]
+        fn printer(p: Rc<StdoutPrinter>) -> Rc<dyn Printer>;
+
+        #[binds]
+        fn boxed_printer(p: Box<StdoutPrinter>) -> Box<dyn Printer>;
+
+        pub fn provide_boxed_stdout_printer() -> Box<StdoutPrinter> {
+            Box::new(StdoutPrinter)
+        }
+    }
+
+    pub trait PrinterComponent {
+        fn printer(&self) -> Rc<dyn Printer>;
+        fn boxed_printer(&self) -> Box<dyn Printer>;
+    }
+}
+
+#[test]
+fn check() {
+    use crate::int_mod::PrinterComponent;
+
+    let injector = crate::int_mod::PrinterComponentImpl::new();
+    assert_eq!("> a", injector.printer().print("a"));
+    assert_eq!("> b", injector.printer().print("b"));
+    assert_eq!("> c", injector.boxed_printer().print("c"));
+    assert_eq!(1, INSTANCES.load(Ordering::SeqCst));
+}
@@ -0,0 +1,70 @@
+use chassis::integration;
+
+pub struct Message(&'static str);
+pub struct PathSeparator(&'static str);
+pub struct Missing;
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct MessageModule;
+
+    impl MessageModule {
+        #[cfg(test)]
+        pub fn provide_message() -> Message {
+            Message("test")
+        }
+
+        #[cfg(not(test))]
+        pub fn provide_message() -> Message {
+            Message("production")
+        }
+    }
+
+    #[cfg(windows)]
+    pub struct WindowsModule;
+
+    #[cfg(windows)]
+    impl WindowsModule {
+        pub fn provide_separator(_missing: Missing) -> PathSeparator {
+            PathSeparator("\\")
+        }
+    }
+
+    pub struct UnixModule;
+
+    #[cfg(not(windows))]
+    impl UnixModule {
+        pub fn provide_separator() -> PathSeparator {
+            PathSeparator("/")
+        }
+    }
+
+    pub trait Component {
+        fn message(&self) -> Message;
+        fn separator(&self) -> PathSeparator;
+    }
+
+    #[cfg(windows)]
+    pub trait WindowsComponent {
+        fn separator(&self) -> PathSeparator;
+    }
+}
+
+#[test]
+fn check_cfg_provider() {
+    use crate::int_mod::Component;
+
+    let injector = crate::int_mod::ComponentImpl::new();
+    assert_eq!("test", injector.message().0);
+}
+
+#[cfg(not(windows))]
+#[test]
+fn check_cfg_module() {
+    use crate::int_mod::Component;
+
+    let injector = crate::int_mod::ComponentImpl::new();
+    assert_eq!("/", injector.separator().0);
+}
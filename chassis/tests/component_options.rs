@@ -0,0 +1,45 @@
+use chassis::integration;
+use std::rc::Rc;
+
+pub struct Printer;
+pub struct Greeter(Rc<Printer>);
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct GreeterModule;
+
+    impl GreeterModule {
+        #[singleton]
+        pub fn provide_printer() -> Rc<Printer> {
+            Rc::new(Printer)
+        }
+
+        pub fn provide_greeter(printer: Rc<Printer>) -> Greeter {
+            Greeter(printer)
+        }
+    }
+
+    #[component(name = "AppInjector", vis = "pub(crate)", derive(Debug))]
+    pub trait GreeterComponent {
+        fn greeter(&self) -> Greeter;
+    }
+}
+
+#[test]
+fn check_name() {
+    use crate::int_mod::GreeterComponent;
+
+    let injector = crate::int_mod::AppInjector::new();
+    assert!(Rc::ptr_eq(&injector.greeter().0, &injector.greeter().0));
+}
+
+#[test]
+fn check_debug() {
+    let injector = crate::int_mod::AppInjector::new();
+    assert_eq!(
+        r#"AppInjector { singleton_types: ["Rc<Printer>"] }"#,
+        format!("{:?}", injector)
+    );
+}
@@ -0,0 +1,89 @@
+use chassis::integration;
+
+#[derive(PartialEq, Debug)]
+pub struct Config(String);
+
+#[derive(PartialEq, Debug)]
+pub struct Server(u16);
+
+#[derive(PartialEq, Debug, Default)]
+pub struct User;
+
+#[derive(PartialEq, Debug, Default)]
+pub struct Order;
+
+#[derive(PartialEq, Debug)]
+pub struct Repository<T>(T);
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct CoreModule;
+
+    impl CoreModule {
+        pub fn provide_config() -> Config {
+            Config("app".to_string())
+        }
+
+        pub fn provide_server() -> Server {
+            Server(8080)
+        }
+
+        pub fn provide_user_repository() -> Repository<User> {
+            Repository(User)
+        }
+
+        pub fn provide_order_repository() -> Repository<Order> {
+            Repository(Order)
+        }
+    }
+
+    pub trait CoreComponent {
+        fn config(&self) -> Config;
+    }
+
+    pub trait WebComponent {
+        fn server(&self) -> Server;
+    }
+
+    pub trait RepositoryComponent<T> {
+        fn repository(&self) -> Repository<T>;
+    }
+
+    pub trait AppComponent: CoreComponent + WebComponent + RepositoryComponent<User> {}
+
+    pub trait ShopComponent: AppComponent + RepositoryComponent<Order> {}
+}
+
+#[test]
+fn check_supertraits() {
+    use crate::int_mod::AppComponent;
+
+    fn use_app(app: &impl AppComponent) {
+        assert_eq!(Config("app".to_string()), app.config());
+        assert_eq!(Server(8080), app.server());
+        assert_eq!(Repository(User), app.repository());
+    }
+
+    use_app(&crate::int_mod::AppComponentImpl::new());
+}
+
+#[test]
+fn check_nested_supertraits() {
+    use crate::int_mod::{RepositoryComponent, ShopComponent};
+
+    fn use_shop(shop: &impl ShopComponent) {
+        assert_eq!(Config("app".to_string()), shop.config());
+        assert_eq!(
+            Repository(Order),
+            RepositoryComponent::<Order>::repository(shop)
+        );
+        assert_eq!(
+            Repository(User),
+            RepositoryComponent::<User>::repository(shop)
+        );
+    }
+
+    use_shop(&crate::int_mod::ShopComponentImpl::new());
+}
@@ -0,0 +1,53 @@
+use chassis::integration;
+use std::time::Duration;
+
+pub struct Config {
+    pub name: &'static str,
+    pub retries: u32,
+}
+
+pub struct Client {
+    timeout: Duration,
+    config: &'static Config,
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    #[provide]
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+    #[provide]
+    static CONFIG: Config = Config {
+        name: "client",
+        retries: 3,
+    };
+
+    pub struct ClientModule;
+
+    impl ClientModule {
+        pub fn provide_client(timeout: Duration, config: &'static Config) -> Client {
+            Client { timeout, config }
+        }
+    }
+
+    pub trait ClientComponent {
+        fn client(&self) -> Client;
+        fn timeout(&self) -> Duration;
+        fn config(&self) -> &'static Config;
+    }
+}
+
+#[test]
+fn check_constants() {
+    use crate::int_mod::ClientComponent;
+
+    let injector = crate::int_mod::ClientComponentImpl::new();
+    let client = injector.client();
+    assert_eq!(Duration::from_secs(30), client.timeout);
+    assert_eq!("client", client.config.name);
+    assert_eq!(3, client.config.retries);
+    assert!(std::ptr::eq(client.config, injector.config()));
+    assert_eq!(Duration::from_secs(30), injector.timeout());
+}
@@ -0,0 +1,48 @@
+use chassis::integration;
+
+#[derive(Default)]
+pub struct Config {
+    retries: u32,
+}
+
+#[derive(Default)]
+pub struct Metrics {
+    requests: u32,
+}
+
+pub struct Client {
+    config: Config,
+    metrics: Metrics,
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct ClientModule;
+
+    impl ClientModule {
+        pub fn provide_client(config: Config, metrics: Metrics) -> Client {
+            Client { config, metrics }
+        }
+
+        pub fn provide_metrics() -> Metrics {
+            Metrics { requests: 42 }
+        }
+    }
+
+    #[component(default_bindings = [Config, Metrics])]
+    pub trait ClientComponent {
+        fn client(&self) -> Client;
+    }
+}
+
+#[test]
+fn check_default_bindings() {
+    use crate::int_mod::ClientComponent;
+
+    let client = crate::int_mod::ClientComponentImpl::new().client();
+    assert_eq!(0, client.config.retries);
+    // providers take precedence
+    assert_eq!(42, client.metrics.requests);
+}
@@ -0,0 +1,87 @@
+use chassis::integration;
+use dyn_chassis::Injector;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Config(&'static str);
+
+pub struct Database {
+    config: Config,
+}
+
+pub trait Printer {
+    fn name(&self) -> &'static str;
+}
+
+pub struct StdoutPrinter;
+
+impl Printer for StdoutPrinter {
+    fn name(&self) -> &'static str {
+        "stdout"
+    }
+}
+
+pub struct Service {
+    database: Arc<Database>,
+    printer: Arc<dyn Printer>,
+}
+
+#[integration(dyn_module)]
+mod int_mod {
+    use super::*;
+
+    pub struct AppModule;
+
+    impl AppModule {
+        pub fn provide_config() -> Config {
+            Config("production")
+        }
+
+        #[singleton]
+        pub fn provide_database(config: Config) -> Arc<Database> {
+            Arc::new(Database { config })
+        }
+
+        pub fn provide_stdout_printer() -> Arc<StdoutPrinter> {
+            Arc::new(StdoutPrinter)
+        }
+
+        #[binds]
+        fn printer(p: Arc<StdoutPrinter>) -> Arc<dyn Printer>;
+
+        pub fn provide_service(database: Arc<Database>, printer: Arc<dyn Printer>) -> Service {
+            Service { database, printer }
+        }
+    }
+
+    pub trait AppComponent {
+        fn service(&self) -> Service;
+    }
+}
+
+#[test]
+fn check_runtime_module() {
+    let injector = Injector::from_module(int_mod::AppModule).unwrap();
+
+    let service = injector.resolve_type::<Service>().unwrap();
+    assert_eq!("production", service.database.config.0);
+    assert_eq!("stdout", service.printer.name());
+    assert_eq!("production", injector.resolve_type::<Config>().unwrap().0);
+}
+
+#[test]
+fn check_runtime_singleton() {
+    let injector = Injector::from_module(int_mod::AppModule).unwrap();
+
+    let first = injector.resolve_type::<Service>().unwrap();
+    let second = injector.resolve_type::<Service>().unwrap();
+    assert!(Arc::ptr_eq(&first.database, &second.database));
+}
+
+#[test]
+fn check_component_still_generated() {
+    use crate::int_mod::AppComponent;
+
+    let service = int_mod::AppComponentImpl::new().service();
+    assert_eq!("stdout", service.printer.name());
+}
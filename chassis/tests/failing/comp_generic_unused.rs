@@ -0,0 +1,14 @@
+use chassis::integration;
+
+pub struct Repository<T>(T);
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub trait RepositoryComponent<T> {
+        fn repository(&self) -> Repository<T>;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use chassis::integration;
+
+pub trait Greeter {}
+
+pub struct EnglishGreeter;
+
+impl Greeter for EnglishGreeter {}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct GreeterModule;
+
+    impl GreeterModule {
+        pub fn provide_greeter() -> EnglishGreeter {
+            EnglishGreeter
+        }
+    }
+
+    pub trait GreeterComponent {
+        fn greeter(&self) -> impl Greeter;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+use chassis::integration;
+
+pub struct Dummy;
+
+mod other {
+    pub trait CoreComponent {}
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct DummyModule;
+
+    impl DummyModule {
+        pub fn provide_dummy() -> Dummy {
+            Dummy
+        }
+    }
+
+    pub trait CoreComponent {
+        fn dummy(&self) -> Dummy;
+    }
+
+    pub trait AppComponent: super::other::CoreComponent {}
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use chassis::integration;
+
+#[integration]
+mod int_mod {
+    #[component(derive(Clone))]
+    pub trait Component {}
+}
+
+fn main() {}
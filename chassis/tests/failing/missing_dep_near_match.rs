@@ -0,0 +1,29 @@
+use chassis::integration;
+
+pub struct Config;
+pub struct Server;
+
+#[integration]
+mod int_mod {
+    use super::*;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    pub struct ServerModule;
+
+    impl ServerModule {
+        pub fn provide_config() -> Rc<Config> {
+            Rc::new(Config)
+        }
+
+        pub fn provide_server(_config: Arc<Config>) -> Server {
+            Server
+        }
+    }
+
+    pub trait ServerFactory {
+        fn resolve_server(&self) -> Server;
+    }
+}
+
+fn main() {}
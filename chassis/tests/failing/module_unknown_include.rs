@@ -0,0 +1,14 @@
+use chassis::integration;
+
+#[integration]
+mod int_mod {
+    pub struct WebModule;
+
+    #[module(includes = [LoggingModule])]
+    impl WebModule {}
+
+    #[component(modules = [WebModule])]
+    pub trait WebComponent {}
+}
+
+fn main() {}
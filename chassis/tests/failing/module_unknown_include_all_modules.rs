@@ -0,0 +1,13 @@
+use chassis::integration;
+
+#[integration]
+mod int_mod {
+    pub struct WebModule;
+
+    #[module(includes = [LoggingModule])]
+    impl WebModule {}
+
+    pub trait WebComponent {}
+}
+
+fn main() {}
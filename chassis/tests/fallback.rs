@@ -0,0 +1,72 @@
+use chassis::integration;
+use dyn_chassis::{AnonymousModule, Injector};
+use std::sync::Arc;
+
+pub trait Plugin: Send + Sync {
+    fn name(&self) -> &'static str;
+}
+
+pub struct GreeterPlugin;
+
+impl Plugin for GreeterPlugin {
+    fn name(&self) -> &'static str {
+        "greeter"
+    }
+}
+
+#[derive(Clone)]
+pub struct Version(u32);
+
+pub struct Host {
+    plugin: Arc<dyn Plugin>,
+    version: Version,
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct HostModule;
+
+    impl HostModule {
+        #[singleton]
+        pub fn provide_host(plugin: Arc<dyn Plugin>, version: Version) -> Arc<Host> {
+            Arc::new(Host { plugin, version })
+        }
+    }
+
+    #[component(fallback = dyn_chassis::Injector)]
+    pub trait HostComponent {
+        fn host(&self) -> Arc<Host>;
+        fn plugin(&self) -> Arc<dyn Plugin>;
+        fn version(&self) -> Version;
+    }
+}
+
+fn plugin_injector() -> Injector {
+    Injector::from_module(AnonymousModule::new(|binder| {
+        binder
+            .bind::<dyn Plugin>()
+            .to_arc_instance(Arc::new(GreeterPlugin));
+        binder.bind::<Version>().to_instance(Version(2));
+    }))
+    .unwrap()
+}
+
+#[test]
+fn check_fallback() {
+    use crate::int_mod::HostComponent;
+
+    let component = int_mod::HostComponentImpl::new(plugin_injector());
+    assert_eq!("greeter", component.plugin().name());
+    assert_eq!(2, component.version().0);
+    assert_eq!("greeter", component.host().plugin.name());
+    assert_eq!(2, component.host().version.0);
+}
+
+#[test]
+#[should_panic(expected = "Failed to resolve")]
+fn check_missing_fallback_binding() {
+    let injector = Injector::from_module(AnonymousModule::new(|_| {})).unwrap();
+    int_mod::HostComponentImpl::new(injector);
+}
@@ -0,0 +1,59 @@
+use chassis::integration;
+
+pub trait Greeter {
+    fn greet(&self) -> String;
+}
+
+pub struct EnglishGreeter(String);
+
+impl Greeter for EnglishGreeter {
+    fn greet(&self) -> String {
+        format!("Hello {}", self.0)
+    }
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct Name(String);
+
+    pub struct GreeterModule;
+
+    impl GreeterModule {
+        pub fn provide_name() -> Name {
+            Name("World".to_string())
+        }
+
+        pub fn provide_greeter(name: Name) -> EnglishGreeter {
+            EnglishGreeter(name.0)
+        }
+
+        pub fn provide_impl_greeter() -> impl Greeter {
+            EnglishGreeter("Impl".to_string())
+        }
+    }
+
+    pub trait GreeterComponent {
+        #[provided_by(GreeterModule::provide_greeter)]
+        fn linked_greeter(&self) -> impl Greeter;
+
+        fn impl_greeter(&self) -> impl Greeter;
+    }
+}
+
+#[test]
+fn check_linked() {
+    use crate::int_mod::GreeterComponent;
+
+    let injector = crate::int_mod::GreeterComponentImpl::new();
+    assert_eq!("Hello World", injector.linked_greeter().greet());
+}
+
+#[test]
+fn check_impl_binding() {
+    use crate::int_mod::GreeterComponent;
+
+    let injector = crate::int_mod::GreeterComponentImpl::new();
+    assert_eq!("Hello Impl", injector.impl_greeter().greet());
+}
@@ -0,0 +1,72 @@
+use chassis::integration;
+
+pub struct Config(&'static str);
+pub struct Logger(&'static str);
+
+pub struct Server {
+    config: Config,
+    logger: Logger,
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct LoggingModule;
+    pub struct ProductionModule;
+    pub struct TestModule;
+    pub struct ServerModule;
+
+    impl LoggingModule {
+        pub fn provide_logger() -> Logger {
+            Logger("stdout")
+        }
+    }
+
+    impl ProductionModule {
+        pub fn provide_config() -> Config {
+            Config("production")
+        }
+    }
+
+    impl TestModule {
+        pub fn provide_config() -> Config {
+            Config("test")
+        }
+    }
+
+    #[module(includes = [LoggingModule])]
+    impl ServerModule {
+        pub fn provide_server(config: Config, logger: Logger) -> Server {
+            Server { config, logger }
+        }
+    }
+
+    #[component(modules = [ServerModule, ProductionModule])]
+    pub trait ProductionComponent {
+        fn server(&self) -> Server;
+    }
+
+    #[component(modules = [ServerModule, TestModule])]
+    pub trait TestComponent {
+        fn server(&self) -> Server;
+    }
+}
+
+#[test]
+fn check_included_modules() {
+    use crate::int_mod::ProductionComponent;
+
+    let server = crate::int_mod::ProductionComponentImpl::new().server();
+    assert_eq!("production", server.config.0);
+    assert_eq!("stdout", server.logger.0);
+}
+
+#[test]
+fn check_component_modules() {
+    use crate::int_mod::TestComponent;
+
+    let server = crate::int_mod::TestComponentImpl::new().server();
+    assert_eq!("test", server.config.0);
+    assert_eq!("stdout", server.logger.0);
+}
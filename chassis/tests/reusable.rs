@@ -0,0 +1,106 @@
+use chassis::integration;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CONNECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Connection(usize);
+
+pub struct UserRepository(Rc<Connection>);
+pub struct OrderRepository(Rc<Connection>);
+
+pub struct Checkout {
+    users: UserRepository,
+    orders: OrderRepository,
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+
+    pub struct RepositoryModule;
+
+    impl RepositoryModule {
+        #[reusable]
+        pub fn provide_connection() -> Rc<Connection> {
+            Rc::new(Connection(CONNECTIONS.fetch_add(1, Ordering::SeqCst)))
+        }
+
+        pub fn provide_users(connection: Rc<Connection>) -> UserRepository {
+            UserRepository(connection)
+        }
+
+        pub fn provide_orders(connection: Rc<Connection>) -> OrderRepository {
+            OrderRepository(connection)
+        }
+
+        pub fn provide_checkout(users: UserRepository, orders: OrderRepository) -> Checkout {
+            Checkout { users, orders }
+        }
+    }
+
+    pub trait CheckoutComponent {
+        fn checkout(&self) -> Checkout;
+    }
+}
+
+#[test]
+fn check_reusable() {
+    use crate::int_mod::CheckoutComponent;
+
+    let injector = crate::int_mod::CheckoutComponentImpl::new();
+    let first = injector.checkout();
+    assert!(Rc::ptr_eq(&first.users.0, &first.orders.0));
+
+    let second = injector.checkout();
+    assert!(Rc::ptr_eq(&second.users.0, &second.orders.0));
+    assert_ne!(first.users.0 .0, second.users.0 .0);
+}
+
+pub struct Cache(usize);
+
+pub struct Dashboard {
+    connection: Rc<Connection>,
+    cache: Rc<Cache>,
+}
+
+#[integration]
+mod two_modules {
+    use super::*;
+
+    pub struct ConnectionModule;
+
+    impl ConnectionModule {
+        #[reusable]
+        pub fn create() -> Rc<Connection> {
+            Rc::new(Connection(CONNECTIONS.fetch_add(1, Ordering::SeqCst)))
+        }
+    }
+
+    pub struct CacheModule;
+
+    impl CacheModule {
+        #[reusable]
+        pub fn create() -> Rc<Cache> {
+            Rc::new(Cache(7))
+        }
+
+        pub fn provide_dashboard(connection: Rc<Connection>, cache: Rc<Cache>) -> Dashboard {
+            Dashboard { connection, cache }
+        }
+    }
+
+    pub trait DashboardComponent {
+        fn dashboard(&self) -> Dashboard;
+    }
+}
+
+#[test]
+fn check_reusable_same_name_in_two_modules() {
+    use crate::two_modules::DashboardComponent;
+
+    let injector = crate::two_modules::DashboardComponentImpl::new();
+    let dashboard = injector.dashboard();
+    assert_eq!(dashboard.cache.0, 7);
+    assert_eq!(Rc::strong_count(&dashboard.connection), 1);
+}
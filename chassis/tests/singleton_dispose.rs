@@ -0,0 +1,72 @@
+use chassis::integration;
+use std::sync::Mutex;
+
+static EVENTS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+fn log(event: &'static str) {
+    EVENTS.lock().unwrap().push(event);
+}
+
+pub struct Pool;
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        log("drop pool");
+    }
+}
+
+pub struct Writer {
+    _pool: std::rc::Rc<Pool>,
+}
+
+impl Drop for Writer {
+    fn drop(&mut self) {
+        log("drop writer");
+    }
+}
+
+#[integration]
+mod int_mod {
+    use super::*;
+    use std::rc::Rc;
+
+    pub fn close_pool(_pool: &Rc<Pool>) {
+        log("close pool");
+    }
+
+    pub fn flush_writer(_writer: &mut Rc<Writer>) {
+        log("flush writer");
+    }
+
+    pub struct TestProvider;
+
+    impl TestProvider {
+        #[singleton(dispose = close_pool)]
+        pub fn provide_pool() -> Rc<Pool> {
+            Rc::new(Pool)
+        }
+
+        #[singleton(dispose = flush_writer)]
+        pub fn provide_writer(pool: Rc<Pool>) -> Rc<Writer> {
+            Rc::new(Writer { _pool: pool })
+        }
+    }
+
+    pub trait TestFactory {
+        fn resolve_writer(&self) -> Rc<Writer>;
+    }
+}
+
+#[test]
+fn check_reverse_dependency_order() {
+    use crate::int_mod::TestFactory;
+
+    let injector = crate::int_mod::TestFactoryImpl::new();
+    drop(injector.resolve_writer());
+    injector.close();
+
+    assert_eq!(
+        vec!["flush writer", "close pool", "drop writer", "drop pool"],
+        *EVENTS.lock().unwrap()
+    );
+}
@@ -1,14 +1,24 @@
+use std::collections::HashMap;
+use std::error::Error;
 use std::marker::PhantomData;
+use std::panic::Location;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::bind::binding::{Binding, BindingType};
 use crate::bind::linker::{LinkedBindings, Linker};
+use crate::bind::multibinder::Multibinding;
+use crate::bind::private::PrivateEnvironment;
 use crate::config::injection_point::InjectionPoint;
 use crate::factory::{
     to_any_factory, ArcCreatingFactory, BoxCreatingFactory, ConstantFactory, CreatingFactory,
+    TryCreatingFactory,
+};
+use crate::scope::{IntoScope, ScopePtr, Scopes};
+use crate::{
+    AnyFactoryRef, BindAnnotation, ChassisError, ChassisResult, Errors, Injector, Key, Module,
+    TypedKey,
 };
-use crate::scope::ScopePtr;
-use crate::{AnyFactoryRef, BindAnnotation, ChassisResult, Injector, Key, Module};
 
 #[cfg(nightly_unsize)]
 use std::marker::Unsize;
@@ -18,17 +28,39 @@ use crate::factory::ConverterBuilder;
 
 pub struct Binder {
     recorded: Vec<RecordedBinding>,
+    private: Vec<PrivateEnvironment>,
+    /// elements of multibindings by key of the multibinding
+    multibindings: HashMap<Key, Rc<dyn Multibinding>>,
+    /// errors found while recording, reported when linking
+    errors: Errors,
 }
 
 impl Binder {
     pub(crate) fn new() -> Self {
         Self {
             recorded: Vec::new(),
+            private: Vec::new(),
+            multibindings: HashMap::new(),
+            errors: Errors::new(),
         }
     }
 
+    /// Bind key of type `T`, the location of the caller is recorded as source of the binding
+    #[track_caller]
     pub fn bind<T: ?Sized + 'static>(&mut self) -> BindingBuilder<T> {
-        let pos = self.bind_any(RecordedBinding::new::<T>());
+        let pos = self.bind_any(RecordedBinding::new::<T>(Location::caller()));
+        BindingBuilder::new(self, pos)
+    }
+
+    /// Bind `key`, the location of the caller is recorded as source of the binding
+    #[track_caller]
+    pub(crate) fn bind_key<T: ?Sized + 'static>(
+        &mut self,
+        key: TypedKey<T>,
+    ) -> BindingBuilder<'_, T> {
+        let mut binding = RecordedBinding::new::<T>(Location::caller());
+        binding.key = key.into();
+        let pos = self.bind_any(binding);
         BindingBuilder::new(self, pos)
     }
 
@@ -37,14 +69,133 @@ impl Binder {
         self.recorded.len() - 1
     }
 
+    pub(crate) fn record(&mut self, binding: RecordedBinding) {
+        self.recorded.push(binding);
+    }
+
+    pub(crate) fn add_private(&mut self, environment: PrivateEnvironment) {
+        self.private.push(environment);
+    }
+
+    /// Elements of the multibinding with `key`, the multibinding is bound by `bind` on first use
+    ///
+    /// Using `key` with another element type is reported when linking and the returned elements
+    /// are not bound.
+    pub(crate) fn multibinding_elements<E: Multibinding + Default>(
+        &mut self,
+        key: Key,
+        source: &'static Location<'static>,
+        bind: impl FnOnce(&mut Binder, Rc<E>),
+    ) -> Rc<E> {
+        if let Some(elements) = self.multibindings.get(&key) {
+            return match Rc::clone(elements).as_any().downcast() {
+                Ok(elements) => elements,
+                Err(_) => {
+                    let first = self
+                        .recorded
+                        .iter()
+                        .find(|binding| {
+                            binding.key == key
+                                && binding.binding_type == Some(BindingType::Multibinding)
+                        })
+                        .map_or_else(
+                            || "multibinding".to_string(),
+                            |binding| format!("Multibinding binding at {}", binding.source),
+                        );
+                    self.errors.add(ChassisError::DuplicateBinding(
+                        key,
+                        first,
+                        format!("multibinding with other element type at {}", source),
+                    ));
+                    Rc::new(E::default())
+                }
+            };
+        }
+
+        let elements = Rc::new(E::default());
+        self.multibindings
+            .insert(key, Rc::clone(&elements) as Rc<dyn Multibinding>);
+        bind(self, Rc::clone(&elements));
+        elements
+    }
+
     /// Install a Module
     #[inline]
     pub fn install(&mut self, module: &dyn Module) {
         module.configure(self)
     }
 
-    pub(crate) fn link(self) -> ChassisResult<LinkedBindings> {
-        Linker::new(self.recorded).link()
+    /// Install `base` with its bindings replaced by the bindings of `overrides` for the same key
+    ///
+    /// Elements of multibindings are combined, unless the multibinding itself is overridden.
+    pub(crate) fn install_overridden(&mut self, base: &dyn Module, overrides: &dyn Module) {
+        let mut base_binder = Binder::new();
+        base_binder.multibindings = self.multibindings.clone();
+        base_binder.install(base);
+        let mut overrides_binder = Binder::new();
+        overrides_binder.multibindings = base_binder.multibindings.clone();
+        overrides_binder.install(overrides);
+
+        let overridden: Vec<&Key> = overrides_binder
+            .recorded
+            .iter()
+            .map(|binding| &binding.key)
+            .collect();
+        let base_recorded: Vec<RecordedBinding> = base_binder
+            .recorded
+            .into_iter()
+            .filter(|binding| !overridden.contains(&&binding.key))
+            .collect();
+
+        self.recorded.extend(base_recorded);
+        self.recorded.extend(overrides_binder.recorded);
+        self.private.extend(base_binder.private);
+        self.private.extend(overrides_binder.private);
+        self.multibindings = overrides_binder.multibindings;
+        self.errors.extend(base_binder.errors);
+        self.errors.extend(overrides_binder.errors);
+    }
+
+    /// Link the recorded bindings, keys of `parent` can be used but not bound again, except the
+    /// `exposed` keys of a private module
+    ///
+    /// Private modules are returned to be linked with the linked bindings as parent.
+    pub(crate) fn link(
+        mut self,
+        parent: Option<&Injector>,
+        exposed: &[Key],
+    ) -> ChassisResult<(LinkedBindings, Vec<PrivateEnvironment>)> {
+        let mut errors = std::mem::replace(&mut self.errors, Errors::new());
+        errors.extend(self.complete_multibindings());
+        match Linker::new(self.recorded, parent, exposed).link() {
+            Ok(linked) if errors.is_empty() => Ok((linked, self.private)),
+            Ok(_) => Err(errors),
+            Err(link_errors) => {
+                errors.extend(link_errors);
+                Err(errors)
+            }
+        }
+    }
+
+    /// Add the elements of the multibindings as their dependencies and check them
+    fn complete_multibindings(&mut self) -> Errors {
+        // sorted to report in the same order for every run
+        let mut multibindings: Vec<(&Key, &Rc<dyn Multibinding>)> =
+            self.multibindings.iter().collect();
+        multibindings.sort_by_key(|(key, _)| key.type_name());
+
+        let mut errors = Errors::new();
+        for (key, elements) in multibindings {
+            // the multibinding can be replaced by an overriding module
+            let binding = self.recorded.iter_mut().find(|binding| {
+                &binding.key == key && binding.binding_type == Some(BindingType::Multibinding)
+            });
+            if let Some(binding) = binding {
+                binding.injection_point = Some(elements.injection_point());
+                elements.check(key, &mut errors);
+            }
+        }
+        errors
     }
 }
 
@@ -54,16 +205,35 @@ pub(crate) struct RecordedBinding {
     key: Key,
     binding_type: Option<BindingType>,
     scope: Option<ScopePtr>,
+    source: &'static Location<'static>,
 }
 
 impl RecordedBinding {
-    pub fn new<T: ?Sized + 'static>() -> Self {
+    pub fn new<T: ?Sized + 'static>(source: &'static Location<'static>) -> Self {
         Self {
             factory: None,
             injection_point: None,
             key: Key::new::<T>(),
             binding_type: None,
             scope: None,
+            source,
+        }
+    }
+
+    /// Binding of `key` to a factory created by chassis, like exposed keys of a private module
+    pub fn targeted(
+        key: Key,
+        factory: AnyFactoryRef,
+        binding_type: BindingType,
+        source: &'static Location<'static>,
+    ) -> Self {
+        Self {
+            factory: Some(factory),
+            injection_point: None,
+            key,
+            binding_type: Some(binding_type),
+            scope: None,
+            source,
         }
     }
 }
@@ -90,6 +260,22 @@ impl<'a, T: 'static> BindingBuilder<'a, T> {
     {
         self.to_any_factory(to_any_factory(CreatingFactory(factory)), injection_point)
     }
+
+    /// Bind to a factory which can fail
+    ///
+    /// A [ResolveError](crate::ResolveError) returned by the factory is propagated, any other error is reported as
+    /// [ChassisError::CreationFailed](crate::ChassisError::CreationFailed) of this key.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_try_factory<U, E>(&mut self, factory: U, injection_point: InjectionPoint)
+    where
+        U: Fn(&Injector) -> Result<T, E> + 'static,
+        E: Into<Box<dyn Error>> + 'static,
+    {
+        self.to_any_factory(
+            to_any_factory::<T, _>(TryCreatingFactory(factory, PhantomData, PhantomData)),
+            injection_point,
+        )
+    }
 }
 
 impl<'a, T: ?Sized + 'static> BindingBuilder<'a, T> {
@@ -117,6 +303,32 @@ impl<'a, T: ?Sized + 'static> BindingBuilder<'a, T> {
         self.to_any_factory(to_any_factory(BoxCreatingFactory(factory)), injection_point)
     }
 
+    /// Like [BindingBuilder::to_try_factory] for factories returning an `Arc`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_try_arc_factory<U, E>(&mut self, factory: U, injection_point: InjectionPoint)
+    where
+        U: Fn(&Injector) -> Result<Arc<T>, E> + 'static,
+        E: Into<Box<dyn Error>> + 'static,
+    {
+        self.to_any_factory(
+            to_any_factory::<T, _>(TryCreatingFactory(factory, PhantomData, PhantomData)),
+            injection_point,
+        )
+    }
+
+    /// Like [BindingBuilder::to_try_factory] for factories returning a `Box`
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_try_box_factory<U, E>(&mut self, factory: U, injection_point: InjectionPoint)
+    where
+        U: Fn(&Injector) -> Result<Box<T>, E> + 'static,
+        E: Into<Box<dyn Error>> + 'static,
+    {
+        self.to_any_factory(
+            to_any_factory::<T, _>(TryCreatingFactory(factory, PhantomData, PhantomData)),
+            injection_point,
+        )
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn to_any_factory(&mut self, factory: AnyFactoryRef, injection_point: InjectionPoint) {
         self.set_factory(factory, BindingType::Factory);
@@ -147,11 +359,16 @@ impl<'a, T: ?Sized + 'static> BindingBuilder<'a, T> {
     }
 
     /// specify scope for binding
-    pub fn in_(&mut self, scope: ScopePtr) -> &mut Self {
-        self.binder.recorded[self.pos].scope = Some(scope);
+    pub fn in_(&mut self, scope: impl IntoScope<T>) -> &mut Self {
+        self.binder.recorded[self.pos].scope = Some(scope.into_scope().0);
         self
     }
 
+    /// Shortcut for `in_(Scopes::SINGLETON)`
+    pub fn in_singleton(&mut self) -> &mut Self {
+        self.in_(Scopes::SINGLETON)
+    }
+
     /// create linked binding to a other type.
     ///
     /// The other type have to be binded.
@@ -178,11 +395,14 @@ impl From<RecordedBinding> for Binding {
         } else {
             factory
         };
+        let eager = recorded.scope.is_some_and(|scope| scope.is_eager());
         Binding::new(
             factory,
             recorded.injection_point,
             recorded.binding_type.unwrap(),
             recorded.key,
+            recorded.source,
+            eager,
         )
     }
 }
@@ -216,4 +436,50 @@ mod tests {
             injection_point.dependencies()[0].key()
         );
     }
+
+    #[derive(Default)]
+    struct Elements1;
+    #[derive(Default)]
+    struct Elements2;
+
+    macro_rules! impl_multibinding {
+        ($elements:ty) => {
+            impl Multibinding for $elements {
+                fn injection_point(&self) -> InjectionPoint {
+                    InjectionPoint::for_module_function("test", &[])
+                }
+
+                fn check(&self, _key: &Key, _errors: &mut Errors) {}
+
+                fn as_any(self: Rc<Self>) -> Rc<dyn std::any::Any> {
+                    self
+                }
+            }
+        };
+    }
+
+    impl_multibinding!(Elements1);
+    impl_multibinding!(Elements2);
+
+    #[test]
+    fn test_multibinding_with_other_element_type() {
+        let mut binder = Binder::new();
+        let key = Key::new::<Class>();
+        binder.multibinding_elements(
+            key.clone(),
+            Location::caller(),
+            |binder, _: Rc<Elements1>| binder.bind::<Class>().to_instance(Class),
+        );
+        binder.multibinding_elements(
+            key.clone(),
+            Location::caller(),
+            |_, _: Rc<Elements2>| unreachable!(),
+        );
+
+        let errors = binder.link(None, &[]).err().expect("errors");
+        assert!(matches!(
+            errors.iter().collect::<Vec<_>>()[..],
+            [ChassisError::DuplicateBinding(ref error_key, _, _)] if error_key == &key
+        ));
+    }
 }
@@ -1,3 +1,6 @@
+use std::fmt;
+use std::panic::Location;
+
 use crate::config::dependency::Dependency;
 use crate::config::injection_point::InjectionPoint;
 use crate::{AnyFactoryRef, BindAnnotation, Key};
@@ -9,6 +12,8 @@ pub enum BindingType {
     Factory,
     Instance,
     Linked,
+    Exposed,
+    Multibinding,
 }
 
 /// A binding
@@ -17,6 +22,8 @@ pub struct Binding {
     injection_point: Option<InjectionPoint>,
     binding_type: BindingType,
     key: Key,
+    source: &'static Location<'static>,
+    eager: bool,
 }
 
 impl Binding {
@@ -25,12 +32,16 @@ impl Binding {
         injection_point: Option<InjectionPoint>,
         binding_type: BindingType,
         key: Key,
+        source: &'static Location<'static>,
+        eager: bool,
     ) -> Self {
         Self {
             factory,
             injection_point,
             binding_type,
             key,
+            source,
+            eager,
         }
     }
 
@@ -62,6 +73,16 @@ impl Binding {
         self.binding_type
     }
 
+    /// Place in source code where the binding was declared
+    pub fn source(&self) -> &'static Location<'static> {
+        self.source
+    }
+
+    /// Binding is created when the injector is built
+    pub fn is_eager(&self) -> bool {
+        self.eager
+    }
+
     /// Dependencies needed to resolve type
     pub fn dependencies(&self) -> &[Dependency] {
         self.injection_point
@@ -69,3 +90,13 @@ impl Binding {
             .map_or(NO_DEPENDENCIES, |ip| ip.dependencies())
     }
 }
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} binding", self.binding_type)?;
+        if let Some(injection_point) = &self.injection_point {
+            write!(f, " `{}`", injection_point.member())?;
+        }
+        write!(f, " at {}", self.source)
+    }
+}
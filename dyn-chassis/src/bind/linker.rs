@@ -1,11 +1,13 @@
 use crate::bind::binder::RecordedBinding;
 use crate::errors::{ChassisError, Errors};
 use crate::meta::Binding;
-use crate::{ChassisResult, Key};
-use std::collections::HashMap;
+use crate::{ChassisResult, Injector, Key};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 
-pub struct Linker {
+pub struct Linker<'a> {
     bindings: HashMap<Key, Binding>,
+    parent: Option<&'a Injector>,
     errors: Errors,
 }
 
@@ -19,22 +21,51 @@ impl LinkedBindings {
     }
 }
 
-impl Linker {
-    pub(crate) fn new(recorded: Vec<RecordedBinding>) -> Self {
-        let binding_map = recorded
-            .into_iter()
-            .map(|binding| binding.into())
-            .map(|binding: Binding| (binding.key(), binding))
-            .collect();
+impl<'a> Linker<'a> {
+    /// `exposed` keys of a private module may be bound again, their parent binding delegates to
+    /// the private binding
+    pub(crate) fn new(
+        recorded: Vec<RecordedBinding>,
+        parent: Option<&'a Injector>,
+        exposed: &[Key],
+    ) -> Self {
+        let mut bindings: HashMap<Key, Binding> = HashMap::new();
+        let mut errors = Errors::new();
+        for binding in recorded {
+            let binding: Binding = binding.into();
+            let inherited = parent
+                .filter(|_| !exposed.contains(&binding.key()))
+                .and_then(|parent| parent.get_binding(binding.key()));
+            if let Some(first) = inherited {
+                errors.add(ChassisError::DuplicateBinding(
+                    binding.key(),
+                    first.to_string(),
+                    binding.to_string(),
+                ));
+                continue;
+            }
+            match bindings.entry(binding.key()) {
+                Entry::Occupied(first) => errors.add(ChassisError::DuplicateBinding(
+                    binding.key(),
+                    first.get().to_string(),
+                    binding.to_string(),
+                )),
+                Entry::Vacant(entry) => {
+                    entry.insert(binding);
+                }
+            }
+        }
 
         Self {
-            bindings: binding_map,
-            errors: Errors::new(),
+            bindings,
+            parent,
+            errors,
         }
     }
 
     pub fn link(mut self) -> ChassisResult<LinkedBindings> {
         self.check_for_missing();
+        self.check_for_cycles();
 
         if self.errors.is_empty() {
             Ok(LinkedBindings {
@@ -48,11 +79,228 @@ impl Linker {
     pub fn check_for_missing(&mut self) {
         for binding in self.bindings.values() {
             for dep in binding.dependencies() {
-                if !self.bindings.contains_key(dep.key()) {
-                    self.errors
-                        .add(ChassisError::MissingImplementation(dep.key().clone()))
+                if !self.bindings.contains_key(dep.key())
+                    && !self
+                        .parent
+                        .is_some_and(|parent| parent.contains_untyped_key(dep.key().clone()))
+                {
+                    self.errors.add(ChassisError::MissingImplementation(
+                        dep.key().clone(),
+                        binding.key(),
+                        binding.to_string(),
+                    ))
                 }
             }
         }
     }
+
+    pub fn check_for_cycles(&mut self) {
+        // sorted to report the same cycles for every run
+        let mut keys: Vec<&Key> = self.bindings.keys().collect();
+        keys.sort_by_key(|key| key.type_name());
+
+        let mut dependents: HashMap<&Key, Vec<&Key>> = HashMap::new();
+        for (key, binding) in &self.bindings {
+            for dep in binding.dependencies() {
+                dependents.entry(dep.key()).or_default().push(key);
+            }
+        }
+
+        // every cycle is reported once from its first key, so keys of earlier roots are done
+        let mut done: HashSet<&Key> = HashSet::new();
+        for key in keys {
+            let candidates = reaching(&dependents, key, &done);
+            find_cycles(
+                &self.bindings,
+                &candidates,
+                &mut vec![key],
+                &mut self.errors,
+            );
+            done.insert(key);
+        }
+    }
+}
+
+/// Keys which depend on `root` directly or indirectly, without going through `done` keys
+fn reaching<'k>(
+    dependents: &HashMap<&'k Key, Vec<&'k Key>>,
+    root: &'k Key,
+    done: &HashSet<&'k Key>,
+) -> HashSet<&'k Key> {
+    let mut reaching = HashSet::new();
+    let mut queue = vec![root];
+    while let Some(key) = queue.pop() {
+        for &dependent in dependents.get(key).into_iter().flatten() {
+            if !done.contains(dependent) && reaching.insert(dependent) {
+                queue.push(dependent);
+            }
+        }
+    }
+    reaching
+}
+
+/// Depth-first search for cycles back to the first key of `path`, `path` are the keys leading
+/// to its last key. Only `candidates` are entered, all other keys can not lead back.
+fn find_cycles<'k>(
+    bindings: &'k HashMap<Key, Binding>,
+    candidates: &HashSet<&Key>,
+    path: &mut Vec<&'k Key>,
+    errors: &mut Errors,
+) {
+    let root = path[0];
+    // missing bindings are reported separately
+    let binding = match bindings.get(path[path.len() - 1]) {
+        Some(binding) => binding,
+        None => return,
+    };
+    for dep in binding.dependencies() {
+        let dep = dep.key();
+        if dep == root {
+            let mut chain: Vec<Key> = path.iter().map(|&key| key.clone()).collect();
+            chain.push(root.clone());
+            errors.add(ChassisError::CyclicDependency(chain));
+        } else if candidates.contains(dep) && !path.contains(&dep) {
+            path.push(dep);
+            find_cycles(bindings, candidates, path, errors);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::meta::InjectionPoint;
+    use crate::{AnonymousModule, ChassisError, Injector, Key};
+
+    struct A;
+    struct B;
+    struct C;
+
+    #[test]
+    fn test_cyclic_dependency() {
+        let errors = Injector::from_module(AnonymousModule::new(|binder| {
+            binder.bind::<A>().to_factory(
+                |_| A,
+                InjectionPoint::for_module_function("provide_a", &[Key::new::<B>()]),
+            );
+            binder.bind::<B>().to_factory(
+                |_| B,
+                InjectionPoint::for_module_function("provide_b", &[Key::new::<C>()]),
+            );
+            binder.bind::<C>().to_factory(
+                |_| C,
+                InjectionPoint::for_module_function("provide_c", &[Key::new::<A>()]),
+            );
+        }))
+        .err()
+        .unwrap();
+
+        let errors: Vec<&ChassisError> = errors.iter().collect();
+        assert_eq!(1, errors.len());
+        assert_matches!(errors[0], ChassisError::CyclicDependency(chain) if chain == &vec![
+            Key::new::<A>(),
+            Key::new::<B>(),
+            Key::new::<C>(),
+            Key::new::<A>(),
+        ]);
+    }
+
+    #[test]
+    fn test_cycles_sharing_keys() {
+        let errors = Injector::from_module(AnonymousModule::new(|binder| {
+            binder.bind::<A>().to_factory(
+                |_| A,
+                InjectionPoint::for_module_function(
+                    "provide_a",
+                    &[Key::new::<B>(), Key::new::<C>()],
+                ),
+            );
+            binder.bind::<B>().to_factory(
+                |_| B,
+                InjectionPoint::for_module_function("provide_b", &[Key::new::<C>()]),
+            );
+            binder.bind::<C>().to_factory(
+                |_| C,
+                InjectionPoint::for_module_function("provide_c", &[Key::new::<A>()]),
+            );
+        }))
+        .err()
+        .unwrap();
+
+        let errors: Vec<&ChassisError> = errors.iter().collect();
+        assert_eq!(2, errors.len());
+        assert_matches!(errors[0], ChassisError::CyclicDependency(chain) if chain == &vec![
+            Key::new::<A>(),
+            Key::new::<B>(),
+            Key::new::<C>(),
+            Key::new::<A>(),
+        ]);
+        assert_matches!(errors[1], ChassisError::CyclicDependency(chain) if chain == &vec![
+            Key::new::<A>(),
+            Key::new::<C>(),
+            Key::new::<A>(),
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_binding() {
+        let errors = Injector::from_module(AnonymousModule::new(|binder| {
+            binder.bind::<A>().to_instance(A);
+            binder
+                .bind::<A>()
+                .to_factory(|_| A, InjectionPoint::for_module_function("provide_a", &[]));
+        }))
+        .err()
+        .unwrap();
+
+        let errors: Vec<&ChassisError> = errors.iter().collect();
+        assert_matches!(
+            errors[..],
+            [ChassisError::DuplicateBinding(key, first, second)]
+                if key == &Key::new::<A>()
+                    && first.starts_with("Instance binding at ")
+                    && second.starts_with("Factory binding `provide_a` at ")
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let errors = Injector::from_module(AnonymousModule::new(|binder| {
+            binder.bind::<A>().to_factory(
+                |_| A,
+                InjectionPoint::for_module_function("provide_a", &[Key::new::<B>()]),
+            );
+        }))
+        .err()
+        .unwrap();
+
+        let report = errors.to_string();
+        let expected_start = format!(
+            "Unable to create injector, see the following errors:\n\n\
+             1) No implementation for `{}` was bound.\n  \
+             required by `{}` (Factory binding `provide_a` at {}:",
+            std::any::type_name::<B>(),
+            std::any::type_name::<A>(),
+            file!()
+        );
+        assert!(report.starts_with(&expected_start), "{}", report);
+        assert!(report.ends_with(")\n\n1 error"), "{}", report);
+    }
+
+    #[test]
+    fn test_self_dependency() {
+        let errors = Injector::from_module(AnonymousModule::new(|binder| {
+            binder.bind::<A>().to_factory(
+                |_| A,
+                InjectionPoint::for_module_function("provide_a", &[Key::new::<A>()]),
+            );
+        }))
+        .err()
+        .unwrap();
+
+        let errors: Vec<&ChassisError> = errors.iter().collect();
+        assert_matches!(errors[..], [ChassisError::CyclicDependency(chain)] if chain.len() == 2);
+    }
 }
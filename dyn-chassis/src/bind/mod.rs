@@ -2,6 +2,8 @@ pub mod annotation;
 pub mod binder;
 pub mod binding;
 pub mod linker;
+pub mod multibinder;
+pub mod private;
 
 #[cfg(test)]
 mod tests {
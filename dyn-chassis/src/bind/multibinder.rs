@@ -0,0 +1,243 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::panic::Location;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::bind::binder::{BindingBuilder, RecordedBinding};
+use crate::bind::binding::BindingType;
+use crate::config::injection_point::InjectionPoint;
+use crate::errors::{ChassisError, Errors};
+use crate::factory::{AnyFactory, Product};
+use crate::{BindAnnotation, Binder, Injector, Key, ResolveError, TypedKey};
+
+static NEXT_ELEMENT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Annotation of the key of an element of a multibinding
+#[derive(Debug)]
+struct Element(#[allow(dead_code)] usize); // only used for the `Debug` representation
+
+impl BindAnnotation for Element {}
+
+fn next_element_key<T: ?Sized + 'static>() -> TypedKey<T> {
+    TypedKey::new_with_annotation(Element(NEXT_ELEMENT_ID.fetch_add(1, Ordering::Relaxed)))
+}
+
+/// Type erased elements of a multibinding, complete when the binder is linked
+pub(crate) trait Multibinding: 'static {
+    /// Injection point with the keys of all elements as dependencies
+    fn injection_point(&self) -> InjectionPoint;
+
+    /// Report elements which can not be combined in the multibinding with `key`
+    fn check(&self, key: &Key, errors: &mut Errors);
+
+    fn as_any(self: Rc<Self>) -> Rc<dyn Any>;
+}
+
+/// Collects bindings of `T` from all modules, which resolve as `Vec<Arc<T>>`
+///
+/// ```
+/// use dyn_chassis::meta::InjectionPoint;
+/// use dyn_chassis::{AnonymousModule, Injector, Multibinder};
+/// use std::sync::Arc;
+///
+/// trait Plugin {}
+/// struct Logging;
+/// impl Plugin for Logging {}
+///
+/// let injector = Injector::from_module(AnonymousModule::new(|binder| {
+///     Multibinder::<dyn Plugin>::new(binder)
+///         .add_binding()
+///         .to_arc_factory(
+///             |_| Arc::new(Logging),
+///             InjectionPoint::for_module_function("logging", &[]),
+///         );
+/// }))
+/// .unwrap();
+///
+/// let plugins = injector.resolve_type::<Vec<Arc<dyn Plugin>>>().unwrap();
+/// assert_eq!(1, plugins.len());
+/// ```
+/// The elements are resolved in the order they were added. Every module creating a
+/// multibinder for `T` contributes to the same multibinding, which is empty if no elements
+/// are added.
+///
+/// A child injector can not add elements to a multibinding of its parent, the multibinding
+/// would be bound again and is reported as a
+/// [DuplicateBinding](crate::ChassisError::DuplicateBinding).
+pub struct Multibinder<'a, T: ?Sized + 'static> {
+    binder: &'a mut Binder,
+    elements: Rc<MultibindingElements<T>>,
+}
+
+struct MultibindingElements<T: ?Sized + 'static>(RefCell<Vec<TypedKey<T>>>);
+
+impl<T: ?Sized + 'static> Default for MultibindingElements<T> {
+    fn default() -> Self {
+        Self(RefCell::new(vec![]))
+    }
+}
+
+impl<T: ?Sized + 'static> Multibinding for MultibindingElements<T> {
+    fn injection_point(&self) -> InjectionPoint {
+        let keys: Vec<Key> = self.0.borrow().iter().cloned().map(Key::from).collect();
+        InjectionPoint::for_module_function("Multibinder::add_binding", &keys)
+    }
+
+    fn check(&self, _key: &Key, _errors: &mut Errors) {}
+
+    fn as_any(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+}
+
+impl<'a, T: ?Sized + 'static> Multibinder<'a, T> {
+    #[track_caller]
+    pub fn new(binder: &'a mut Binder) -> Self {
+        let source = Location::caller();
+        let elements = binder.multibinding_elements(
+            Key::new::<Vec<Arc<T>>>(),
+            source,
+            |binder, elements: Rc<MultibindingElements<T>>| {
+                binder.record(RecordedBinding::targeted(
+                    Key::new::<Vec<Arc<T>>>(),
+                    Arc::new(MultibindingFactory { elements }),
+                    BindingType::Multibinding,
+                    source,
+                ));
+            },
+        );
+        Self { binder, elements }
+    }
+
+    /// Add an element, which has to be bound with the returned builder
+    #[track_caller]
+    pub fn add_binding(&mut self) -> BindingBuilder<'_, T> {
+        let key = next_element_key::<T>();
+        self.elements.0.borrow_mut().push(key.clone());
+        self.binder.bind_key(key)
+    }
+}
+
+struct MultibindingFactory<T: ?Sized + 'static> {
+    elements: Rc<MultibindingElements<T>>,
+}
+
+impl<T: ?Sized + 'static> AnyFactory for MultibindingFactory<T> {
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
+        let elements: Result<Vec<Arc<T>>, ResolveError> = self
+            .elements
+            .0
+            .borrow()
+            .iter()
+            .map(|key| injector.try_resolve(key.clone()))
+            .collect();
+        Ok(Product::new(Arc::new(elements?)))
+    }
+}
+
+/// Collects bindings of `V` by `K` from all modules, which resolve as `HashMap<K, Arc<V>>`
+///
+/// Like [Multibinder], but every element is added with a key. Adding the same key twice is
+/// reported when the injector is built.
+pub struct MapBinder<'a, K: 'static, V: ?Sized + 'static> {
+    binder: &'a mut Binder,
+    entries: Rc<MapBindingEntries<K, V>>,
+}
+
+struct MapBindingEntries<K: 'static, V: ?Sized + 'static>(RefCell<Vec<(K, TypedKey<V>)>>);
+
+impl<K: 'static, V: ?Sized + 'static> Default for MapBindingEntries<K, V> {
+    fn default() -> Self {
+        Self(RefCell::new(vec![]))
+    }
+}
+
+impl<K, V> Multibinding for MapBindingEntries<K, V>
+where
+    K: Hash + Eq + Clone + Debug + 'static,
+    V: ?Sized + 'static,
+{
+    fn injection_point(&self) -> InjectionPoint {
+        let keys: Vec<Key> = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(_, element_key)| element_key.clone().into())
+            .collect();
+        InjectionPoint::for_module_function("MapBinder::add_binding", &keys)
+    }
+
+    fn check(&self, key: &Key, errors: &mut Errors) {
+        let entries = self.0.borrow();
+        for (i, (map_key, _)) in entries.iter().enumerate() {
+            // every duplicate is only reported for its first occurrence
+            let count = entries.iter().filter(|(other, _)| other == map_key).count();
+            if count > 1 && !entries[..i].iter().any(|(other, _)| other == map_key) {
+                errors.add(ChassisError::DuplicateMapKey(
+                    key.clone(),
+                    format!("{:?}", map_key),
+                ));
+            }
+        }
+    }
+
+    fn as_any(self: Rc<Self>) -> Rc<dyn Any> {
+        self
+    }
+}
+
+impl<'a, K, V> MapBinder<'a, K, V>
+where
+    K: Hash + Eq + Clone + Debug + 'static,
+    V: ?Sized + 'static,
+{
+    #[track_caller]
+    pub fn new(binder: &'a mut Binder) -> Self {
+        let source = Location::caller();
+        let entries = binder.multibinding_elements(
+            Key::new::<HashMap<K, Arc<V>>>(),
+            source,
+            |binder, entries: Rc<MapBindingEntries<K, V>>| {
+                binder.record(RecordedBinding::targeted(
+                    Key::new::<HashMap<K, Arc<V>>>(),
+                    Arc::new(MapBindingFactory { entries }),
+                    BindingType::Multibinding,
+                    source,
+                ));
+            },
+        );
+        Self { binder, entries }
+    }
+
+    /// Add an element for `key`, which has to be bound with the returned builder
+    #[track_caller]
+    pub fn add_binding(&mut self, key: K) -> BindingBuilder<'_, V> {
+        let element_key = next_element_key::<V>();
+        self.entries.0.borrow_mut().push((key, element_key.clone()));
+        self.binder.bind_key(element_key)
+    }
+}
+
+struct MapBindingFactory<K: 'static, V: ?Sized + 'static> {
+    entries: Rc<MapBindingEntries<K, V>>,
+}
+
+impl<K, V> AnyFactory for MapBindingFactory<K, V>
+where
+    K: Hash + Eq + Clone + Debug + 'static,
+    V: ?Sized + 'static,
+{
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
+        // duplicate keys are reported when the injector is built
+        let mut map: HashMap<K, Arc<V>> = HashMap::new();
+        for (key, element_key) in self.entries.0.borrow().iter() {
+            map.insert(key.clone(), injector.try_resolve(element_key.clone())?);
+        }
+        Ok(Product::new(Arc::new(map)))
+    }
+}
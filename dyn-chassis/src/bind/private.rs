@@ -0,0 +1,138 @@
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+
+use crate::bind::binder::RecordedBinding;
+use crate::bind::binding::BindingType;
+use crate::factory::{AnyFactory, Product};
+use crate::{BindAnnotation, Binder, Injector, Key, ResolveError};
+
+/// Injector of a private module, set when the private module is linked
+///
+/// Owned by the injector installing the private module, whose bindings refer to it.
+pub(crate) type PrivateBindings = Rc<OnceCell<Weak<Injector>>>;
+
+/// Bindings of a private module and the keys it exposes
+pub(crate) struct PrivateEnvironment {
+    pub binder: Binder,
+    pub exposed: Vec<Key>,
+    pub bindings: PrivateBindings,
+}
+
+/// Binder of a [PrivateModule](crate::PrivateModule)
+///
+/// Bindings are only visible inside the private module, unless they are exposed.
+pub struct PrivateBinder {
+    binder: Binder,
+    exposed: Vec<(Key, &'static Location<'static>)>,
+}
+
+impl PrivateBinder {
+    /// Make the binding of `T` visible outside of the private module
+    #[track_caller]
+    pub fn expose<T: ?Sized + 'static>(&mut self) -> ExposedBuilder<'_, T> {
+        ExposedBuilder {
+            exposed: &mut self.exposed,
+            key: Key::new::<T>(),
+            source: Location::caller(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl Deref for PrivateBinder {
+    type Target = Binder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.binder
+    }
+}
+
+impl DerefMut for PrivateBinder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.binder
+    }
+}
+
+/// Exposes the key when it is dropped
+pub struct ExposedBuilder<'a, T: ?Sized + 'static> {
+    exposed: &'a mut Vec<(Key, &'static Location<'static>)>,
+    key: Key,
+    source: &'static Location<'static>,
+    ty: PhantomData<T>,
+}
+
+impl<'a, T: ?Sized + 'static> ExposedBuilder<'a, T> {
+    /// Expose the binding with `annotation`
+    pub fn annotated_with<U: BindAnnotation>(&mut self, annotation: U) -> &mut Self {
+        self.key = self.key.clone().with_annotation(annotation);
+        self
+    }
+}
+
+impl<'a, T: ?Sized + 'static> Drop for ExposedBuilder<'a, T> {
+    fn drop(&mut self) {
+        self.exposed.push((self.key.clone(), self.source));
+    }
+}
+
+impl Binder {
+    /// Install a private module, only its exposed keys are bound in this binder
+    pub fn install_private(&mut self, module: &dyn crate::PrivateModule) {
+        let mut private = PrivateBinder {
+            binder: Binder::new(),
+            exposed: vec![],
+        };
+        module.configure(&mut private);
+
+        let bindings = PrivateBindings::default();
+        for (key, source) in &private.exposed {
+            let factory = ExposedFactory {
+                key: key.clone(),
+                bindings: Rc::clone(&bindings),
+            };
+            // factories are owned by an injector, which is neither `Send` nor `Sync`
+            #[allow(clippy::arc_with_non_send_sync)]
+            let factory = Arc::new(factory);
+            self.record(RecordedBinding::targeted(
+                key.clone(),
+                factory,
+                BindingType::Exposed,
+                source,
+            ));
+        }
+        self.add_private(PrivateEnvironment {
+            binder: private.binder,
+            exposed: private.exposed.into_iter().map(|(key, _)| key).collect(),
+            bindings,
+        });
+    }
+}
+
+/// Resolves a key in the bindings of a private module
+struct ExposedFactory {
+    key: Key,
+    bindings: PrivateBindings,
+}
+
+impl AnyFactory for ExposedFactory {
+    fn load(&self, _injector: &Injector) -> Result<Product, ResolveError> {
+        let private = match self.bindings.get().and_then(Weak::upgrade) {
+            Some(private) => private,
+            None => {
+                return Err(ResolveError::creation_failed(
+                    "private module is not linked".into(),
+                ))
+            }
+        };
+        match private.get_binding(self.key.clone()) {
+            Some(binding) => binding.factory().load(&private),
+            None => Err(ResolveError::creation_failed(
+                "exposed key is not bound in the private module".into(),
+            )),
+        }
+    }
+}
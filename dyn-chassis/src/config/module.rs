@@ -1,4 +1,4 @@
-use crate::Binder;
+use crate::{Binder, PrivateBinder};
 
 /// A injection module
 pub trait Module {
@@ -6,6 +6,45 @@ pub trait Module {
     fn configure(&self, binder: &mut Binder);
 }
 
+/// A module whose bindings are only visible to each other, unless they are exposed
+///
+/// Like in Guice, private modules can bind helpers with the same key as other modules:
+/// ```
+/// use dyn_chassis::meta::InjectionPoint;
+/// use dyn_chassis::{Injector, Named, PrivateBinder, PrivateModule};
+/// use std::sync::Arc;
+///
+/// struct Pool;
+/// struct Storage(Arc<Pool>);
+///
+/// struct StorageModule;
+///
+/// impl PrivateModule for StorageModule {
+///     fn configure(&self, binder: &mut PrivateBinder) {
+///         binder.bind::<Pool>().annotated_with(Named("pool")).to_instance(Pool);
+///         binder.bind::<Storage>().to_factory(
+///             |injector| Storage(injector.resolve_annotated(Named("pool")).unwrap()),
+///             InjectionPoint::for_module_function("storage", &[]),
+///         );
+///         binder.expose::<Storage>();
+///     }
+/// }
+///
+/// let injector = Injector::from_module(StorageModule).unwrap();
+/// assert!(injector.resolve_type::<Storage>().is_some());
+/// assert!(injector.resolve_annotated::<Pool, _>(Named("pool")).is_none());
+/// ```
+pub trait PrivateModule {
+    /// Configure bindings
+    fn configure(&self, binder: &mut PrivateBinder);
+}
+
+impl<T: PrivateModule> Module for T {
+    fn configure(&self, binder: &mut Binder) {
+        binder.install_private(self);
+    }
+}
+
 /// A module backed by a function
 pub struct AnonymousModule<T: Fn(&mut Binder)>(T);
 
@@ -20,3 +59,44 @@ impl<T: Fn(&mut Binder)> Module for AnonymousModule<T> {
         self.0(binder);
     }
 }
+
+/// Functions to combine modules
+pub struct Modules;
+
+impl Modules {
+    /// Replace bindings of `base` with the bindings of another module.
+    ///
+    /// Like in Guice: `Modules::overriding(ProductionModule).with(TestModule)`
+    pub fn overriding(base: impl Module + 'static) -> OverridingModuleBuilder {
+        OverridingModuleBuilder {
+            base: Box::new(base),
+        }
+    }
+}
+
+/// Builder for [OverridingModule]
+pub struct OverridingModuleBuilder {
+    base: Box<dyn Module>,
+}
+
+impl OverridingModuleBuilder {
+    /// Use bindings of `overrides` instead of bindings of the base module with the same key
+    pub fn with(self, overrides: impl Module + 'static) -> OverridingModule {
+        OverridingModule {
+            base: self.base,
+            overrides: Box::new(overrides),
+        }
+    }
+}
+
+/// A module whose bindings are partially replaced by the bindings of another module
+pub struct OverridingModule {
+    base: Box<dyn Module>,
+    overrides: Box<dyn Module>,
+}
+
+impl Module for OverridingModule {
+    fn configure(&self, binder: &mut Binder) {
+        binder.install_overridden(self.base.as_ref(), self.overrides.as_ref());
+    }
+}
@@ -0,0 +1,229 @@
+use crate::factory::{next_factory_id, AnyFactory, Product};
+use crate::scope::ScopeRef;
+use crate::{AnyFactoryRef, Injector, IntoScope, Key, ResolveError, Scope};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+thread_local!(
+    /// entered scope contexts, the innermost context is the last one
+    static CONTEXTS: RefCell<Vec<Arc<ScopeContext>>> = const { RefCell::new(Vec::new()) }
+);
+
+/// Scope whose instances are cached in an explicitly entered context
+///
+/// ```
+/// use dyn_chassis::ContextScope;
+///
+/// static REQUEST_SCOPE: ContextScope = ContextScope::new("request");
+/// ```
+///
+/// Bindings `in_(&REQUEST_SCOPE)` are cached until the guard returned by
+/// [Injector::enter_scope] is dropped, or for the whole [Injector::scoped] future. Resolving
+/// them outside of a context fails.
+///
+/// A scoped future can move between threads, so only `Send + Sync` types can be bound in a
+/// context scope:
+/// ```compile_fail
+/// # use dyn_chassis::{AnonymousModule, ContextScope};
+/// # use std::rc::Rc;
+/// static REQUEST_SCOPE: ContextScope = ContextScope::new("request");
+///
+/// AnonymousModule::new(|binder| {
+///     binder.bind::<Rc<u32>>().in_(&REQUEST_SCOPE).to_instance(Rc::new(0));
+/// });
+/// ```
+pub struct ContextScope {
+    scope: ContextScopeImpl,
+}
+
+impl ContextScope {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            scope: ContextScopeImpl { name },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.scope.name
+    }
+}
+
+impl<T: ?Sized + Send + Sync> IntoScope<T> for &'static ContextScope {
+    fn into_scope(self) -> ScopeRef {
+        ScopeRef(&self.scope)
+    }
+}
+
+impl fmt::Debug for ContextScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.scope.fmt(f)
+    }
+}
+
+/// Innermost entered context of `scope`
+fn current_context(scope: *const ContextScopeImpl) -> Option<Arc<ScopeContext>> {
+    CONTEXTS.with(|contexts| {
+        contexts
+            .borrow()
+            .iter()
+            .rev()
+            .find(|context| std::ptr::eq(&context.scope.scope, scope))
+            .cloned()
+    })
+}
+
+/// Scope of a [ContextScope], only reachable for `Send + Sync` bindings
+struct ContextScopeImpl {
+    name: &'static str,
+}
+
+impl Scope for ContextScopeImpl {
+    // factories are owned by an injector, which is neither `Send` nor `Sync`
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn scope(&self, _key: &Key, unscoped: AnyFactoryRef) -> AnyFactoryRef {
+        Arc::new(ContextFactory {
+            unscoped,
+            scope: self,
+            name: self.name,
+            id: next_factory_id(),
+        })
+    }
+}
+
+impl fmt::Debug for ContextScopeImpl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ContextScope({})", self.name)
+    }
+}
+
+/// Product of a binding in a context scope
+struct SendProduct(Product);
+
+// SAFETY: every `SendProduct` holds an `Arc<T>` with `T: Send + Sync`, which is `Send` and
+// `Sync` itself. This relies on the following invariant:
+// * `SendProduct`s are only created by `ContextFactory`, from products of the factory of a
+//   binding of `T`, which are always `Arc<T>`
+// * `ContextFactory` is only created by the private `ContextScopeImpl`
+// * a binding only gets a `ContextScopeImpl` through the `ScopeRef` returned by
+//   `IntoScope<T> for &'static ContextScope`, which requires `T: Send + Sync`, and `ScopeRef`
+//   can not be created outside of this crate
+unsafe impl Send for SendProduct {}
+unsafe impl Sync for SendProduct {}
+
+/// Products of the bindings of a scope created in one context
+pub(crate) struct ScopeContext {
+    scope: &'static ContextScope,
+    products: Mutex<HashMap<usize, SendProduct>>,
+}
+
+impl ScopeContext {
+    fn new(scope: &'static ContextScope) -> Arc<Self> {
+        Arc::new(Self {
+            scope,
+            products: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn products(&self) -> MutexGuard<'_, HashMap<usize, SendProduct>> {
+        self.products.lock().expect("Poisoned scope context mutex")
+    }
+
+    fn enter(self: &Arc<Self>) -> ScopeGuard {
+        CONTEXTS.with(|contexts| contexts.borrow_mut().push(Arc::clone(self)));
+        ScopeGuard {
+            context: Arc::clone(self),
+            thread_bound: PhantomData,
+        }
+    }
+}
+
+/// Keeps a scope context entered on the current thread until it is dropped
+#[must_use = "the scope context is left when the guard is dropped"]
+pub struct ScopeGuard {
+    context: Arc<ScopeContext>,
+    /// has to be dropped on the thread where the context was entered
+    thread_bound: PhantomData<*const ()>,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        CONTEXTS.with(|contexts| {
+            let mut contexts = contexts.borrow_mut();
+            if let Some(pos) = contexts
+                .iter()
+                .rposition(|context| Arc::ptr_eq(context, &self.context))
+            {
+                contexts.remove(pos);
+            }
+        });
+    }
+}
+
+/// Future running in its own scope context, see [Injector::scoped]
+///
+/// Is `Send` if the inner future is, the context is entered on the thread which polls it.
+pub struct Scoped<F> {
+    context: Arc<ScopeContext>,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for Scoped<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let _guard = self.context.enter();
+        self.future.as_mut().poll(cx)
+    }
+}
+
+impl Injector {
+    /// Enter a new context of `scope` on the current thread
+    pub fn enter_scope(&self, scope: &'static ContextScope) -> ScopeGuard {
+        ScopeContext::new(scope).enter()
+    }
+
+    /// Run `future` in a new context of `scope`, which is entered every time it is polled
+    pub fn scoped<F: Future>(&self, scope: &'static ContextScope, future: F) -> Scoped<F> {
+        Scoped {
+            context: ScopeContext::new(scope),
+            future: Box::pin(future),
+        }
+    }
+}
+
+struct ContextFactory {
+    unscoped: AnyFactoryRef,
+    /// only used to identify contexts of the scope
+    scope: *const ContextScopeImpl,
+    name: &'static str,
+    id: usize,
+}
+
+impl AnyFactory for ContextFactory {
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
+        let context = current_context(self.scope).ok_or_else(|| {
+            ResolveError::creation_failed(
+                format!("scope `{}` is not entered on this thread", self.name).into(),
+            )
+        })?;
+
+        if let Some(product) = context.products().get(&self.id) {
+            return Ok(product.0.clone());
+        }
+
+        // not locked while loading, dependencies can be in the same scope
+        let product = self.unscoped.load(injector)?;
+        let mut products = context.products();
+        Ok(products
+            .entry(self.id)
+            .or_insert(SendProduct(product))
+            .0
+            .clone())
+    }
+}
@@ -4,9 +4,59 @@ use std::fmt;
 
 #[derive(Debug)]
 pub enum ChassisError {
-    MissingImplementation(Key),
-    // CyclicDependency(Vec<Dependency>),
-    // CreateError(Box<dyn-chassis Error>),
+    /// missing key, key of the binding requiring it and description of that binding
+    MissingImplementation(Key, Key, String),
+    /// key exposed by a private module without binding it and description of the exposure
+    ExposedNotBound(Key, String),
+    /// chain of keys, the first and the last key are the same
+    CyclicDependency(Vec<Key>),
+    /// key bound twice, descriptions of the first and the second binding
+    DuplicateBinding(Key, String, String),
+    /// key of a map binding and the map key which was added twice
+    DuplicateMapKey(Key, String),
+    /// key whose factory failed and the error returned by the factory
+    CreationFailed(Key, Box<dyn Error>),
+    /// eager binding could not be created when the injector was built
+    EagerCreationFailed(ResolveError),
+}
+
+impl fmt::Display for ChassisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChassisError::MissingImplementation(key, required_by, binding) => write!(
+                f,
+                "No implementation for {} was bound.\n  required by {} ({})",
+                key, required_by, binding
+            ),
+            ChassisError::ExposedNotBound(key, exposed) => write!(
+                f,
+                "{} was exposed, but not bound in the private module.\n  exposed by {}",
+                key, exposed
+            ),
+            ChassisError::CyclicDependency(chain) => {
+                f.write_str("Cyclic dependency ")?;
+                for (i, key) in chain.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" -> ")?;
+                    }
+                    write!(f, "{}", key)?;
+                }
+                Ok(())
+            }
+            ChassisError::DuplicateBinding(key, first, second) => write!(
+                f,
+                "{} was already bound.\n  first bound by {}\n  bound again by {}",
+                key, first, second
+            ),
+            ChassisError::DuplicateMapKey(key, map_key) => {
+                write!(f, "Key {} was added twice to {}", map_key, key)
+            }
+            ChassisError::CreationFailed(key, error) => {
+                write!(f, "Failed to create {}: {}", key, error)
+            }
+            ChassisError::EagerCreationFailed(error) => write!(f, "{}", error),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -23,15 +73,29 @@ impl Errors {
         self.errors.push(error)
     }
 
+    pub(crate) fn extend(&mut self, other: Errors) {
+        self.errors.extend(other.errors)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ChassisError> {
+        self.errors.iter()
+    }
 }
 
 impl fmt::Display for Errors {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // TODO: do better
-        f.debug_list().entries(&self.errors).finish()
+        writeln!(f, "Unable to create injector, see the following errors:")?;
+        for (i, error) in self.errors.iter().enumerate() {
+            writeln!(f, "\n{}) {}", i + 1, error)?;
+        }
+        match self.errors.len() {
+            1 => write!(f, "\n1 error"),
+            n => write!(f, "\n{} errors", n),
+        }
     }
 }
 
@@ -44,3 +108,95 @@ impl fmt::Debug for Errors {
 impl Error for Errors {}
 
 pub type ChassisResult<T> = Result<T, Errors>;
+
+/// Failure to resolve a key at runtime
+#[derive(Debug)]
+pub struct ResolveError {
+    /// keys from the requested key to the key that could not be resolved
+    chain: Vec<Key>,
+    cause: Box<Cause>,
+}
+
+#[derive(Debug)]
+enum Cause {
+    /// no binding exists for the last key of the chain
+    Missing,
+    /// factory failed, the key of the factory is added by [ResolveError::required_by]
+    Creating(Box<dyn Error>),
+    /// factory of the last key of the chain failed
+    Failed(ChassisError),
+}
+
+impl ResolveError {
+    /// No binding exists for `key`
+    pub(crate) fn missing(key: Key) -> Self {
+        Self {
+            chain: vec![key],
+            cause: Box::new(Cause::Missing),
+        }
+    }
+
+    /// The factory currently loaded failed with `error`
+    pub(crate) fn creation_failed(error: Box<dyn Error>) -> Self {
+        Self {
+            chain: vec![],
+            cause: Box::new(Cause::Creating(error)),
+        }
+    }
+
+    /// Record that resolving `key` failed because of this error
+    pub(crate) fn required_by(mut self, key: Key) -> Self {
+        if let Cause::Creating(error) = *self.cause {
+            self.cause = Box::new(Cause::Failed(ChassisError::CreationFailed(
+                key.clone(),
+                error,
+            )));
+        }
+        self.chain.insert(0, key);
+        self
+    }
+
+    /// Key that could not be resolved
+    pub fn key(&self) -> &Key {
+        self.chain.last().expect("empty key chain")
+    }
+
+    /// Keys from the requested key to the key that could not be resolved
+    pub fn chain(&self) -> &[Key] {
+        &self.chain
+    }
+
+    /// Error of the failed factory, `None` if no binding was found
+    pub fn cause(&self) -> Option<&ChassisError> {
+        match self.cause.as_ref() {
+            Cause::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.cause.as_ref() {
+            Cause::Missing => write!(f, "Failed to resolve {}: no binding found", self.key())?,
+            Cause::Creating(error) => write!(f, "Failed to create: {}", error)?,
+            Cause::Failed(error) => write!(f, "{}", error)?,
+        }
+        for key in self.chain.iter().rev().skip(1) {
+            write!(f, "\n  required by {}", key)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ResolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.cause.as_ref() {
+            Cause::Missing => None,
+            Cause::Creating(error) | Cause::Failed(ChassisError::CreationFailed(_, error)) => {
+                Some(error.as_ref())
+            }
+            Cause::Failed(_) => None,
+        }
+    }
+}
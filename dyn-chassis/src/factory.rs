@@ -1,13 +1,15 @@
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 
 use crate::debug::{get_type_name, save_type_name};
-use crate::Injector;
+use crate::{Injector, ResolveError};
 use std::any::{type_name, Any};
+use std::error::Error;
 use std::ops::Deref;
 
 pub(crate) trait Factory<T: ?Sized + 'static> {
-    fn load(&self, injector: &Injector) -> Arc<T>;
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError>;
 
     // fn into_trait_loader<Trait: ?Sized + 'static>(self) -> ConverterFactory<Trait, T, Self>
     // where
@@ -31,6 +33,7 @@ pub(crate) trait Factory<T: ?Sized + 'static> {
 /// Is a interface to Arc<?>.
 trait ProductAny: Any + 'static {
     fn clone_product(&self) -> Product;
+    fn downgrade_product(&self) -> WeakProduct;
     fn as_any(self: Box<Self>) -> Box<dyn Any>;
 }
 
@@ -39,6 +42,10 @@ impl<T: ?Sized + 'static> ProductAny for Arc<T> {
         Product::new(self.clone())
     }
 
+    fn downgrade_product(&self) -> WeakProduct {
+        WeakProduct(Box::new(Arc::downgrade(self)))
+    }
+
     fn as_any(self: Box<Self>) -> Box<dyn Any> {
         self
     }
@@ -52,6 +59,10 @@ impl Product {
         Self(Box::new(value))
     }
 
+    pub(crate) fn downgrade(&self) -> WeakProduct {
+        self.0.downgrade_product()
+    }
+
     pub fn unwrap<T: ?Sized + 'static>(self) -> Arc<T> {
         match self.0.as_any().downcast::<Arc<T>>() {
             Ok(result) => *result,
@@ -73,15 +84,44 @@ impl Clone for Product {
     }
 }
 
+/// Interface to type erased form of a weak reference to a factory product
+///
+/// Is a interface to Weak<?>.
+trait WeakProductAny: 'static {
+    fn upgrade_product(&self) -> Option<Product>;
+}
+
+impl<T: ?Sized + 'static> WeakProductAny for Weak<T> {
+    fn upgrade_product(&self) -> Option<Product> {
+        self.upgrade().map(Product::new)
+    }
+}
+
+/// Weak reference to a product of a factory
+pub(crate) struct WeakProduct(Box<dyn WeakProductAny>); // aka. Box<Weak<?>>
+
+impl WeakProduct {
+    pub fn upgrade(&self) -> Option<Product> {
+        self.0.upgrade_product()
+    }
+}
+
 /// type erased version of [Factory](chassis::Factory)
 ///
 /// TODO: make into sealed trait
 pub trait AnyFactory {
-    fn load(&self, injector: &Injector) -> Product;
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError>;
 }
 
 pub type AnyFactoryRef = Arc<dyn AnyFactory>;
 
+static NEXT_FACTORY_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Unique id to store products of a scoped factory outside of the factory
+pub(crate) fn next_factory_id() -> usize {
+    NEXT_FACTORY_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 pub(crate) fn to_any_factory<T: ?Sized + 'static, U: Factory<T> + 'static>(
     other: U,
 ) -> AnyFactoryRef {
@@ -91,26 +131,26 @@ pub(crate) fn to_any_factory<T: ?Sized + 'static, U: Factory<T> + 'static>(
 pub(crate) struct AnyFactoryImpl<T: ?Sized + 'static, U: Factory<T> + 'static>(U, PhantomData<T>);
 
 impl<T: ?Sized + 'static, U: Factory<T> + 'static> AnyFactory for AnyFactoryImpl<T, U> {
-    fn load(&self, service_locator: &Injector) -> Product {
+    fn load(&self, service_locator: &Injector) -> Result<Product, ResolveError> {
         save_type_name::<T>();
         save_type_name::<Arc<T>>();
-        Product::new(self.0.load(service_locator))
+        self.0.load(service_locator).map(Product::new)
     }
 }
 
 pub(crate) struct ConstantFactory<T: ?Sized + 'static>(pub Arc<T>);
 
 impl<T: ?Sized + 'static> Factory<T> for ConstantFactory<T> {
-    fn load(&self, _injector: &Injector) -> Arc<T> {
-        Arc::clone(&self.0)
+    fn load(&self, _injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        Ok(Arc::clone(&self.0))
     }
 }
 
 pub(crate) struct CreatingFactory<T: 'static, F: Fn(&Injector) -> T>(pub F);
 
 impl<T: 'static, F: Fn(&Injector) -> T> Factory<T> for CreatingFactory<T, F> {
-    fn load(&self, injector: &Injector) -> Arc<T> {
-        Arc::new(self.0(injector))
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        Ok(Arc::new(self.0(injector)))
     }
 }
 
@@ -124,8 +164,8 @@ where
     T: ?Sized + 'static,
     F: Fn(&Injector) -> Arc<T>,
 {
-    fn load(&self, injector: &Injector) -> Arc<T> {
-        self.0(injector)
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        Ok(self.0(injector))
     }
 }
 
@@ -139,8 +179,40 @@ where
     T: ?Sized + 'static,
     F: Fn(&Injector) -> Box<T>,
 {
-    fn load(&self, injector: &Injector) -> Arc<T> {
-        self.0(injector).into()
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        Ok(self.0(injector).into())
+    }
+}
+
+/// Factory whose creation can fail, the product is converted into `Arc<T>`
+///
+/// A [ResolveError] returned by the factory is propagated, any other error is reported as
+/// [ChassisError::CreationFailed](crate::ChassisError::CreationFailed).
+pub(crate) struct TryCreatingFactory<T, R, E, F>(
+    pub F,
+    pub PhantomData<(R, E)>,
+    pub PhantomData<fn() -> T>,
+)
+where
+    T: ?Sized + 'static,
+    R: Into<Arc<T>>,
+    E: Into<Box<dyn Error>>,
+    F: Fn(&Injector) -> Result<R, E>;
+
+impl<T, R, E, F> Factory<T> for TryCreatingFactory<T, R, E, F>
+where
+    T: ?Sized + 'static,
+    R: Into<Arc<T>>,
+    E: Into<Box<dyn Error>>,
+    F: Fn(&Injector) -> Result<R, E>,
+{
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        self.0(injector).map(Into::into).map_err(|error| {
+            match error.into().downcast::<ResolveError>() {
+                Ok(error) => *error,
+                Err(error) => ResolveError::creation_failed(error),
+            }
+        })
     }
 }
 
@@ -186,11 +258,7 @@ where
     U: ?Sized + 'static,
     L: Fn(Arc<U>) -> Arc<T>,
 {
-    fn load(&self, injector: &Injector) -> Arc<T> {
-        self.0(
-            injector
-                .resolve_type::<U>()
-                .expect("missing dependencies for linked binding"),
-        )
+    fn load(&self, injector: &Injector) -> Result<Arc<T>, ResolveError> {
+        injector.try_resolve_type::<U>().map(&self.0)
     }
 }
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::rc::Rc;
 use std::sync::Arc;
 
 use crate::bind::binding::Binding;
@@ -6,7 +7,10 @@ use crate::factory::Product;
 use crate::inject::builder::InjectorBuilder;
 use crate::key::TypedKey;
 use crate::resolve::ResolveInto;
-use crate::{BindAnnotation, Binder, ChassisResult, Key, Module, Provider};
+use crate::{
+    BindAnnotation, Binder, ChassisError, ChassisResult, Errors, Key, Module, Provider,
+    ResolveError,
+};
 
 pub mod builder;
 
@@ -16,15 +20,112 @@ pub mod builder;
 
 /// Holds factories of all registered types.
 pub struct Injector {
-    bindings: HashMap<Key, Binding>,
+    bindings: Rc<HashMap<Key, Binding>>,
+    parent: Option<Rc<Injector>>,
+    /// injectors of the private modules, their exposed bindings only refer to them weakly
+    private: Vec<Rc<Injector>>,
 }
 
 impl Injector {
     /// for tests only
     pub(crate) fn from_binder(binder: Binder) -> ChassisResult<Self> {
-        Ok(Self {
-            bindings: binder.link()?.bindings(),
-        })
+        Self::from_binder_with_parent(binder, None)
+    }
+
+    fn from_binder_with_parent(
+        binder: Binder,
+        parent: Option<Rc<Injector>>,
+    ) -> ChassisResult<Self> {
+        let injector = Self::link(binder, parent, &[])?;
+
+        let mut errors = Errors::new();
+        injector.create_eager_bindings(&mut errors);
+        if errors.is_empty() {
+            Ok(injector)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Link bindings of `binder`, its private modules are linked as children
+    fn link(binder: Binder, parent: Option<Rc<Injector>>, exposed: &[Key]) -> ChassisResult<Self> {
+        let (linked, environments) = binder.link(parent.as_deref(), exposed)?;
+        let mut injector = Self {
+            bindings: Rc::new(linked.bindings()),
+            parent,
+            private: vec![],
+        };
+
+        // shared before the children are added, so they are not kept alive by themselves
+        let shared = Rc::new(injector.share());
+        let mut errors = Errors::new();
+        for environment in environments {
+            let parent = Some(Rc::clone(&shared));
+            let child = match Self::link(environment.binder, parent, &environment.exposed) {
+                Ok(child) => child,
+                Err(child_errors) => {
+                    errors.extend(child_errors);
+                    continue;
+                }
+            };
+            for key in environment.exposed {
+                if !child.bindings.contains_key(&key) {
+                    let exposed = injector
+                        .bindings
+                        .get(&key)
+                        .map_or_else(|| "private module".to_string(), Binding::to_string);
+                    errors.add(ChassisError::ExposedNotBound(key, exposed));
+                }
+            }
+            let child = Rc::new(child);
+            let _ = environment.bindings.set(Rc::downgrade(&child));
+            injector.private.push(child);
+        }
+
+        if errors.is_empty() {
+            Ok(injector)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Create an injector resolving the bindings of `module` and falling back to this injector
+    ///
+    /// Bindings of this injector, including its singletons, are shared with the child. Binding a
+    /// key again which is already bound in this injector is an error.
+    pub fn create_child(&self, module: impl Module + 'static) -> ChassisResult<Injector> {
+        let mut binder = Binder::new();
+        binder.install(&module);
+        Self::from_binder_with_parent(binder, Some(Rc::new(self.share())))
+    }
+
+    /// Injector with the same bindings
+    fn share(&self) -> Injector {
+        Self {
+            bindings: Rc::clone(&self.bindings),
+            parent: self.parent.clone(),
+            private: self.private.clone(),
+        }
+    }
+
+    fn create_eager_bindings(&self, errors: &mut Errors) {
+        // sorted to create and report in the same order for every run
+        let mut keys: Vec<Key> = self
+            .bindings
+            .values()
+            .filter(|binding| binding.is_eager())
+            .map(Binding::key)
+            .collect();
+        keys.sort_by_key(|key| key.type_name());
+
+        for key in keys {
+            if let Err(err) = self.try_resolve_any(key) {
+                errors.add(ChassisError::EagerCreationFailed(err));
+            }
+        }
+        for private in &self.private {
+            private.create_eager_bindings(errors);
+        }
     }
 
     pub fn builder() -> InjectorBuilder {
@@ -46,7 +147,7 @@ impl Injector {
     }
 
     pub fn contains_untyped_key(&self, key: Key) -> bool {
-        self.bindings.contains_key(&key)
+        self.get_binding(key).is_some()
     }
 
     #[inline]
@@ -62,19 +163,58 @@ impl Injector {
         self.resolve(TypedKey::<T>::new_with_annotation(annotation))
     }
 
+    /// Resolve `key`, `None` if `key` is not bound.
+    ///
+    /// Panics if a dependency of `key` can not be resolved, use [Injector::try_resolve] to
+    /// handle that failure.
     pub fn resolve<T: ?Sized + 'static>(&self, key: TypedKey<T>) -> Option<Arc<T>> {
-        self.resolve_any(key.into()).map(|product| product.unwrap())
+        let key: Key = key.into();
+        if !self.contains_untyped_key(key.clone()) {
+            return None;
+        }
+
+        match self.try_resolve_any(key) {
+            Ok(product) => product.map(|product| product.unwrap()),
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    #[inline]
+    pub fn try_resolve_type<T: ?Sized + 'static>(&self) -> Result<Arc<T>, ResolveError> {
+        self.try_resolve(TypedKey::<T>::new())
+    }
+
+    /// Resolve `key`, fails if `key` or one of its dependencies can not be resolved.
+    #[inline]
+    pub fn try_resolve<T: ?Sized + 'static>(
+        &self,
+        key: TypedKey<T>,
+    ) -> Result<Arc<T>, ResolveError> {
+        self.try_resolve_to(key)
     }
 
-    fn resolve_any(&self, key: Key) -> Option<Product> {
-        self.bindings
-            .get(&key)
-            .map(|binding| binding.factory().load(self))
+    fn try_resolve_any(&self, key: Key) -> Result<Option<Product>, ResolveError> {
+        match self.bindings.get(&key) {
+            Some(binding) => match binding.factory().load(self) {
+                Ok(product) => Ok(Some(product)),
+                Err(err) => Err(err.required_by(key)),
+            },
+            None => match &self.parent {
+                Some(parent) => parent.try_resolve_any(key),
+                None => Ok(None),
+            },
+        }
     }
 
     /// Only use in the context of tooling!
     pub fn get_binding(&self, key: Key) -> Option<&Binding> {
-        self.bindings.get(&key)
+        match self.bindings.get(&key) {
+            Some(binding) => Some(binding),
+            None => self
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.get_binding(key)),
+        }
     }
 
     #[inline]
@@ -82,6 +222,15 @@ impl Injector {
         U::resolve_into(self.resolve(TypedKey::clone(&key)), &key)
     }
 
+    /// Like [Injector::resolve_to], but fails instead of panicking
+    pub fn try_resolve_to<T: ?Sized + 'static, U: ResolveInto<Item = T>>(
+        &self,
+        key: TypedKey<T>,
+    ) -> Result<U, ResolveError> {
+        let product = self.try_resolve_any(Key::clone(&key))?;
+        U::try_resolve_into(product.map(|product| product.unwrap()), &key)
+    }
+
     // #[inline]
     // pub fn to_provider<T: ?Sized + 'static>(&self) -> ProviderPtr<T> {
     //     assert!(self.contains::<T>());
@@ -102,7 +251,7 @@ mod tests {
     use std::fmt::Debug;
 
     use super::*;
-    use crate::AnonymousModule;
+    use crate::{AnonymousModule, ChassisError, Modules};
 
     trait Interface1: Debug {
         fn do_something(&self);
@@ -120,6 +269,12 @@ mod tests {
     #[derive(Eq, PartialEq, Debug)]
     struct Impl2();
 
+    impl Interface1 for Impl2 {
+        fn do_something(&self) {
+            // no nothing
+        }
+    }
+
     #[test]
     fn test_resolve_existing_struct() {
         let locator = Injector::from_module(AnonymousModule::new(|binder| {
@@ -144,6 +299,26 @@ mod tests {
         assert_eq!(None, locator.resolve_type::<Impl1>());
     }
 
+    #[test]
+    fn test_resolve_overridden() {
+        let base = AnonymousModule::new(|binder| {
+            binder.bind::<Impl1>().to_instance(Impl1());
+            binder
+                .bind::<dyn Interface1>()
+                .to_arc_instance(Arc::new(Impl1()));
+        });
+        let overrides = AnonymousModule::new(|binder| {
+            binder
+                .bind::<dyn Interface1>()
+                .to_arc_instance(Arc::new(Impl2()));
+        });
+        let locator = Injector::from_module(Modules::overriding(base).with(overrides)).unwrap();
+
+        assert_eq!(Some(Arc::new(Impl1())), locator.resolve_type::<Impl1>());
+        let overridden = locator.resolve_type::<dyn Interface1>().unwrap();
+        assert_eq!("Impl2", format!("{:?}", overridden));
+    }
+
     #[test]
     fn test_resolve_nonexisting() {
         let locator = Injector::from_binder(Binder::new()).unwrap();
@@ -152,6 +327,33 @@ mod tests {
         assert_eq!(None, locator.resolve_type::<Impl1>());
     }
 
+    #[test]
+    fn test_singleton_does_not_cache_failure() {
+        use crate::meta::InjectionPoint;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let factory_calls = Arc::clone(&calls);
+        let locator = Injector::from_module(AnonymousModule::new(move |binder| {
+            let calls = Arc::clone(&factory_calls);
+            binder.bind::<Impl1>().in_singleton().to_try_factory(
+                move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err("not ready"),
+                    _ => Ok(Impl1()),
+                },
+                InjectionPoint::for_module_function("create", &[]),
+            );
+        }))
+        .unwrap();
+
+        let err = locator.try_resolve_type::<Impl1>().err().unwrap();
+        assert_matches!(err.cause(), Some(ChassisError::CreationFailed(_, _)));
+        let first = locator.try_resolve_type::<Impl1>().unwrap();
+        let second = locator.try_resolve_type::<Impl1>().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
     /*    #[test]
     fn it_works() {
         //let x: *const dyn-chassis Interface1 = std::ptr::null();
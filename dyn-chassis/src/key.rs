@@ -107,6 +107,16 @@ impl fmt::Debug for Key {
     }
 }
 
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`", self.type_name)?;
+        if let Some(annotation) = self.annotation_debug() {
+            write!(f, " annotated with {}", annotation)?;
+        }
+        Ok(())
+    }
+}
+
 impl<T: ?Sized + 'static> TypedKey<T> {
     /// Create from only a type.
     #[inline]
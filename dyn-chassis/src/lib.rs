@@ -5,18 +5,24 @@ pub use dyn_chassis_proc_macros::{factory, inject, module};
 
 pub use crate::bind::annotation::{BindAnnotation, Named};
 pub use crate::bind::binder::Binder;
-pub use crate::config::module::{AnonymousModule, Module};
-pub use crate::errors::{ChassisError, ChassisResult, Errors};
+pub use crate::bind::multibinder::{MapBinder, Multibinder};
+pub use crate::bind::private::{ExposedBuilder, PrivateBinder};
+pub use crate::config::module::{
+    AnonymousModule, Module, Modules, OverridingModule, OverridingModuleBuilder, PrivateModule,
+};
+pub use crate::context::{ContextScope, ScopeGuard, Scoped};
+pub use crate::errors::{ChassisError, ChassisResult, Errors, ResolveError};
 pub(crate) use crate::factory::AnyFactoryRef;
 pub use crate::helper::*;
 pub use crate::inject::Injector;
 pub use crate::key::{Key, TypedKey};
 pub use crate::provider::{Provider, ProviderPtr};
 pub use crate::resolve::ResolveInto;
-pub use crate::scope::Scope;
+pub use crate::scope::{IntoScope, Scope, Scopes};
 
 mod bind;
 mod config;
+mod context;
 mod inject;
 
 mod debug;
@@ -28,6 +34,8 @@ mod provider;
 mod resolve;
 mod scope;
 mod singleton;
+mod thread_local;
+mod weak;
 
 #[doc(hidden)]
 pub mod _internal {
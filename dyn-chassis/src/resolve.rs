@@ -1,12 +1,26 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::Key;
+use crate::{Key, ResolveError};
 
 /// Resolve result into
 pub trait ResolveInto {
     type Item: ?Sized + 'static;
 
     fn resolve_into(result: Option<Arc<Self::Item>>, key: &Key) -> Self;
+
+    /// Like [ResolveInto::resolve_into], but fails instead of panicking
+    ///
+    /// By default a missing binding fails, override it if `None` is a valid result.
+    fn try_resolve_into(result: Option<Arc<Self::Item>>, key: &Key) -> Result<Self, ResolveError>
+    where
+        Self: Sized,
+    {
+        match result {
+            Some(_) => Ok(Self::resolve_into(result, key)),
+            None => Err(ResolveError::missing(key.clone())),
+        }
+    }
 }
 
 // impl<T: ?Sized + Clone + 'static> ResolveFrom for ProviderPtr<T> {
@@ -21,6 +35,10 @@ impl<T: ?Sized + 'static> ResolveInto for Arc<T> {
     fn resolve_into(result: Option<Arc<T>>, key: &Key) -> Self {
         result.unwrap_or_else(|| panic!("Failed to resolve {:?}", key))
     }
+
+    fn try_resolve_into(result: Option<Arc<T>>, key: &Key) -> Result<Self, ResolveError> {
+        result.ok_or_else(|| ResolveError::missing(key.clone()))
+    }
 }
 
 impl<T: ?Sized + 'static> ResolveInto for Option<Arc<T>> {
@@ -29,4 +47,37 @@ impl<T: ?Sized + 'static> ResolveInto for Option<Arc<T>> {
     fn resolve_into(result: Option<Arc<T>>, _key: &Key) -> Self {
         result
     }
+
+    fn try_resolve_into(result: Option<Arc<T>>, _key: &Key) -> Result<Self, ResolveError> {
+        Ok(result)
+    }
+}
+
+/// Elements of a [Multibinder](crate::Multibinder)
+impl<T: ?Sized + 'static> ResolveInto for Vec<Arc<T>> {
+    type Item = Vec<Arc<T>>;
+
+    fn resolve_into(result: Option<Arc<Vec<Arc<T>>>>, key: &Key) -> Self {
+        Vec::clone(&Arc::resolve_into(result, key))
+    }
+
+    fn try_resolve_into(result: Option<Arc<Vec<Arc<T>>>>, key: &Key) -> Result<Self, ResolveError> {
+        Arc::try_resolve_into(result, key).map(|elements| Vec::clone(&elements))
+    }
+}
+
+/// Elements of a [MapBinder](crate::MapBinder)
+impl<K: Clone + 'static, V: ?Sized + 'static> ResolveInto for HashMap<K, Arc<V>> {
+    type Item = HashMap<K, Arc<V>>;
+
+    fn resolve_into(result: Option<Arc<HashMap<K, Arc<V>>>>, key: &Key) -> Self {
+        HashMap::clone(&Arc::resolve_into(result, key))
+    }
+
+    fn try_resolve_into(
+        result: Option<Arc<HashMap<K, Arc<V>>>>,
+        key: &Key,
+    ) -> Result<Self, ResolveError> {
+        Arc::try_resolve_into(result, key).map(|elements| HashMap::clone(&elements))
+    }
 }
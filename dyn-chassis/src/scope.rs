@@ -1,8 +1,53 @@
+use crate::singleton::{EagerSingletonScope, SingletonScope};
+use crate::thread_local::ThreadLocalScope;
+use crate::weak::WeakScope;
 use crate::{AnyFactoryRef, Key};
 use std::fmt::Debug;
 
 pub trait Scope: Debug {
     fn scope(&self, key: &Key, unscoped: AnyFactoryRef) -> AnyFactoryRef;
+
+    /// Create the scoped binding when the injector is built
+    fn is_eager(&self) -> bool {
+        false
+    }
 }
 
 pub(crate) type ScopePtr = &'static dyn Scope;
+
+/// Scope usable for bindings of `T` in `BindingBuilder::in_`
+pub trait IntoScope<T: ?Sized> {
+    fn into_scope(self) -> ScopeRef;
+}
+
+/// Scope checked for a binding type, can not be used for other bindings
+pub struct ScopeRef(pub(crate) ScopePtr);
+
+impl<T: ?Sized> IntoScope<T> for &'static dyn Scope {
+    fn into_scope(self) -> ScopeRef {
+        ScopeRef(self)
+    }
+}
+
+impl<T: ?Sized, S: Scope> IntoScope<T> for &'static S {
+    fn into_scope(self) -> ScopeRef {
+        ScopeRef(self)
+    }
+}
+
+/// Built-in scopes
+pub struct Scopes;
+
+impl Scopes {
+    /// One instance per injector, created on first use
+    pub const SINGLETON: &'static dyn Scope = &SingletonScope;
+
+    /// One instance per injector, created when the injector is built
+    pub const EAGER_SINGLETON: &'static dyn Scope = &EagerSingletonScope;
+
+    /// One instance per injector and thread, dropped with the injector
+    pub const THREAD_LOCAL: &'static dyn Scope = &ThreadLocalScope;
+
+    /// Instance is reused as long as a `Arc` of it is alive
+    pub const WEAK: &'static dyn Scope = &WeakScope;
+}
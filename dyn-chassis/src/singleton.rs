@@ -1,5 +1,5 @@
 use crate::factory::{AnyFactory, Product};
-use crate::{AnyFactoryRef, Injector, Key, Scope};
+use crate::{AnyFactoryRef, Injector, Key, ResolveError, Scope};
 use std::fmt;
 use std::ops::Deref;
 use std::sync::{Arc, Mutex};
@@ -21,23 +21,41 @@ impl fmt::Debug for SingletonScope {
     }
 }
 
+pub(crate) struct EagerSingletonScope;
+
+impl Scope for EagerSingletonScope {
+    fn scope(&self, key: &Key, unscoped: AnyFactoryRef) -> AnyFactoryRef {
+        SingletonScope.scope(key, unscoped)
+    }
+
+    fn is_eager(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for EagerSingletonScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("chassis::EAGER_SINGLETON")
+    }
+}
+
 struct SingletonFactory {
     unscoped: AnyFactoryRef,
     maybe_contructed: Mutex<Option<Product>>, // TODO: Use RWLOCK?
 }
 
 impl AnyFactory for SingletonFactory {
-    fn load(&self, injector: &Injector) -> Product {
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
         let mut maybe_constructed = self
             .maybe_contructed
             .lock()
             .expect("Poisoned singleton mutex");
         if let Some(product) = maybe_constructed.deref() {
-            product.clone()
+            Ok(product.clone())
         } else {
-            let product = self.unscoped.load(injector);
+            let product = self.unscoped.load(injector)?;
             *maybe_constructed = Some(product.clone());
-            product
+            Ok(product)
         }
     }
 }
@@ -0,0 +1,45 @@
+use crate::factory::{AnyFactory, Product};
+use crate::{AnyFactoryRef, Injector, Key, ResolveError, Scope};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+pub(crate) struct ThreadLocalScope;
+
+impl Scope for ThreadLocalScope {
+    // factories are owned by an injector, which is neither `Send` nor `Sync`
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn scope(&self, _key: &Key, unscoped: AnyFactoryRef) -> AnyFactoryRef {
+        Arc::new(ThreadLocalFactory {
+            unscoped,
+            products: Mutex::new(HashMap::new()),
+        })
+    }
+}
+
+impl fmt::Debug for ThreadLocalScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("chassis::THREAD_LOCAL")
+    }
+}
+
+struct ThreadLocalFactory {
+    unscoped: AnyFactoryRef,
+    /// products by thread, dropped with the factory
+    products: Mutex<HashMap<ThreadId, Product>>,
+}
+
+impl AnyFactory for ThreadLocalFactory {
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
+        let thread = thread::current().id();
+        let products = || self.products.lock().expect("Poisoned thread local mutex");
+        if let Some(product) = products().get(&thread) {
+            return Ok(product.clone());
+        }
+
+        // not locked while loading, dependencies can be thread local too
+        let product = self.unscoped.load(injector)?;
+        Ok(products().entry(thread).or_insert(product).clone())
+    }
+}
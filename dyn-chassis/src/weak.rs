@@ -0,0 +1,41 @@
+use crate::factory::{AnyFactory, Product, WeakProduct};
+use crate::{AnyFactoryRef, Injector, Key, ResolveError, Scope};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+pub(crate) struct WeakScope;
+
+impl Scope for WeakScope {
+    // factories are owned by an injector, which is neither `Send` nor `Sync`
+    #[allow(clippy::arc_with_non_send_sync)]
+    fn scope(&self, _key: &Key, unscoped: AnyFactoryRef) -> AnyFactoryRef {
+        Arc::new(WeakFactory {
+            unscoped,
+            maybe_alive: Mutex::new(None),
+        })
+    }
+}
+
+impl fmt::Debug for WeakScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("chassis::WEAK")
+    }
+}
+
+struct WeakFactory {
+    unscoped: AnyFactoryRef,
+    maybe_alive: Mutex<Option<WeakProduct>>,
+}
+
+impl AnyFactory for WeakFactory {
+    fn load(&self, injector: &Injector) -> Result<Product, ResolveError> {
+        let mut maybe_alive = self.maybe_alive.lock().expect("Poisoned weak mutex");
+        if let Some(product) = maybe_alive.as_ref().and_then(WeakProduct::upgrade) {
+            Ok(product)
+        } else {
+            let product = self.unscoped.load(injector)?;
+            *maybe_alive = Some(product.downgrade());
+            Ok(product)
+        }
+    }
+}
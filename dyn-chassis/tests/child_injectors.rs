@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use dyn_chassis::{module, ChassisError, Injector, Key};
+
+struct Database;
+
+struct Tenant(&'static str);
+
+struct TenantService {
+    tenant: Arc<Tenant>,
+    database: Arc<Database>,
+}
+
+struct AppModule;
+
+#[module]
+impl AppModule {
+    #[singleton]
+    pub fn provide_database() -> Database {
+        Database
+    }
+}
+
+struct TenantModule(&'static str);
+
+impl dyn_chassis::Module for TenantModule {
+    fn configure(&self, binder: &mut dyn_chassis::Binder) {
+        binder.bind::<Tenant>().to_instance(Tenant(self.0));
+        binder.install(&TenantServiceModule);
+    }
+}
+
+struct TenantServiceModule;
+
+#[module]
+impl TenantServiceModule {
+    pub fn provide_service(tenant: Arc<Tenant>, database: Arc<Database>) -> TenantService {
+        TenantService { tenant, database }
+    }
+}
+
+#[test]
+fn child_falls_back_to_parent() {
+    let app = Injector::from_module(AppModule).unwrap();
+    let tenant = app.create_child(TenantModule("a")).unwrap();
+
+    let service = tenant.resolve_type::<TenantService>().unwrap();
+    assert_eq!("a", service.tenant.0);
+    assert!(tenant.contains_type::<Database>());
+    assert!(!app.contains_type::<Tenant>());
+}
+
+#[test]
+fn children_share_parent_singletons() {
+    let app = Injector::from_module(AppModule).unwrap();
+    let a = app.create_child(TenantModule("a")).unwrap();
+    let b = app.create_child(TenantModule("b")).unwrap();
+
+    let service_a = a.resolve_type::<TenantService>().unwrap();
+    let service_b = b.resolve_type::<TenantService>().unwrap();
+    assert_eq!("b", service_b.tenant.0);
+    assert!(Arc::ptr_eq(&service_a.database, &service_b.database));
+    assert!(Arc::ptr_eq(
+        &service_a.database,
+        &app.resolve_type::<Database>().unwrap()
+    ));
+}
+
+#[test]
+fn child_can_not_rebind_parent_key() {
+    let app = Injector::from_module(AppModule).unwrap();
+    let errors = app.create_child(AppModule).err().unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::DuplicateBinding(key, _, _)] => assert_eq!(&Key::new::<Database>(), key),
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
+
+#[test]
+fn child_dependencies_are_checked() {
+    let app = Injector::from_module(AppModule).unwrap();
+    let errors = app.create_child(TenantServiceModule).err().unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::MissingImplementation(key, _, _)] => assert_eq!(&Key::new::<Tenant>(), key),
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
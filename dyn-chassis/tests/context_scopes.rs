@@ -0,0 +1,138 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use dyn_chassis::meta::InjectionPoint;
+use dyn_chassis::{AnonymousModule, ContextScope, Injector};
+
+static REQUEST_SCOPE: ContextScope = ContextScope::new("request");
+static SESSION_SCOPE: ContextScope = ContextScope::new("session");
+
+struct Transaction(usize);
+struct Session(usize);
+
+fn injector() -> Injector {
+    let transactions = Arc::new(AtomicUsize::new(0));
+    let sessions = Arc::new(AtomicUsize::new(0));
+    Injector::from_module(AnonymousModule::new(move |binder| {
+        let transactions = Arc::clone(&transactions);
+        binder.bind::<Transaction>().in_(&REQUEST_SCOPE).to_factory(
+            move |_| Transaction(transactions.fetch_add(1, Ordering::SeqCst)),
+            InjectionPoint::for_module_function("begin", &[]),
+        );
+        let sessions = Arc::clone(&sessions);
+        binder.bind::<Session>().in_(&SESSION_SCOPE).to_factory(
+            move |_| Session(sessions.fetch_add(1, Ordering::SeqCst)),
+            InjectionPoint::for_module_function("login", &[]),
+        );
+    }))
+    .unwrap()
+}
+
+#[test]
+fn cached_until_guard_is_dropped() {
+    let injector = injector();
+
+    {
+        let _request = injector.enter_scope(&REQUEST_SCOPE);
+        let first = injector.resolve_type::<Transaction>().unwrap();
+        let second = injector.resolve_type::<Transaction>().unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(0, first.0);
+    }
+
+    let _request = injector.enter_scope(&REQUEST_SCOPE);
+    assert_eq!(1, injector.resolve_type::<Transaction>().unwrap().0);
+}
+
+#[test]
+fn nested_scopes() {
+    let injector = injector();
+
+    let _session = injector.enter_scope(&SESSION_SCOPE);
+    for request in 0..2 {
+        let _request = injector.enter_scope(&REQUEST_SCOPE);
+        assert_eq!(request, injector.resolve_type::<Transaction>().unwrap().0);
+        assert_eq!(0, injector.resolve_type::<Session>().unwrap().0);
+    }
+}
+
+#[test]
+fn fails_outside_of_scope() {
+    let injector = injector();
+
+    let err = injector.try_resolve_type::<Transaction>().err().unwrap();
+    assert_eq!(
+        "Failed to create `context_scopes::Transaction`: \
+         scope `request` is not entered on this thread",
+        err.to_string()
+    );
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Yields once before completing
+struct YieldOnce(bool);
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            Poll::Pending
+        }
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+thread_local!(static INJECTOR: Injector = injector());
+
+fn resolve_transaction() -> Arc<Transaction> {
+    INJECTOR.with(|injector| injector.resolve_type::<Transaction>().unwrap())
+}
+
+#[test]
+fn scoped_future_on_other_thread() {
+    let future = injector().scoped(&REQUEST_SCOPE, async {
+        let first = resolve_transaction();
+        YieldOnce(false).await;
+        (first, resolve_transaction())
+    });
+
+    let (first, second) = std::thread::spawn(move || block_on(future)).join().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn scoped_future() {
+    let injector = injector();
+
+    let (first, second) = block_on(injector.scoped(&REQUEST_SCOPE, async {
+        let first = injector.resolve_type::<Transaction>().unwrap();
+        YieldOnce(false).await;
+        let second = injector.resolve_type::<Transaction>().unwrap();
+        (first, second)
+    }));
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(injector.try_resolve_type::<Transaction>().is_err());
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use dyn_chassis::meta::InjectionPoint;
+use dyn_chassis::{
+    module, AnonymousModule, Binder, ChassisError, Injector, Key, MapBinder, Modules, Multibinder,
+};
+
+trait Plugin {
+    fn name(&self) -> &'static str;
+}
+
+struct NamedPlugin(&'static str);
+
+impl Plugin for NamedPlugin {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+fn plugin_module(name: &'static str) -> AnonymousModule<impl Fn(&mut Binder)> {
+    AnonymousModule::new(move |binder| {
+        Multibinder::<dyn Plugin>::new(binder)
+            .add_binding()
+            .to_arc_factory(
+                move |_| Arc::new(NamedPlugin(name)),
+                InjectionPoint::for_module_function("plugin", &[]),
+            );
+    })
+}
+
+struct Host {
+    plugins: Vec<Arc<dyn Plugin>>,
+}
+
+struct HostModule;
+
+#[module]
+impl HostModule {
+    pub fn provide_host(plugins: Vec<Arc<dyn Plugin>>) -> Host {
+        Host { plugins }
+    }
+}
+
+#[test]
+fn modules_contribute_to_multibinding() {
+    let injector = Injector::builder()
+        .module(HostModule)
+        .module(plugin_module("a"))
+        .module(plugin_module("b"))
+        .build()
+        .unwrap();
+
+    let host = injector.resolve_type::<Host>().unwrap();
+    let names: Vec<&str> = host.plugins.iter().map(|plugin| plugin.name()).collect();
+    assert_eq!(vec!["a", "b"], names);
+}
+
+#[test]
+fn multibinding_without_elements_is_empty() {
+    let injector = Injector::builder()
+        .module(HostModule)
+        .module(AnonymousModule::new(|binder| {
+            Multibinder::<dyn Plugin>::new(binder);
+        }))
+        .build()
+        .unwrap();
+
+    assert!(injector.resolve_type::<Host>().unwrap().plugins.is_empty());
+}
+
+#[test]
+fn elements_can_be_scoped() {
+    let injector = Injector::from_module(AnonymousModule::new(|binder| {
+        Multibinder::<NamedPlugin>::new(binder)
+            .add_binding()
+            .in_singleton()
+            .to_factory(
+                |_| NamedPlugin("singleton"),
+                InjectionPoint::for_module_function("plugin", &[]),
+            );
+    }))
+    .unwrap();
+
+    let first = injector.resolve_type::<Vec<Arc<NamedPlugin>>>().unwrap();
+    let second = injector.resolve_type::<Vec<Arc<NamedPlugin>>>().unwrap();
+    assert!(Arc::ptr_eq(&first[0], &second[0]));
+}
+
+fn command_module(name: &'static str) -> AnonymousModule<impl Fn(&mut Binder)> {
+    AnonymousModule::new(move |binder| {
+        MapBinder::<&'static str, dyn Plugin>::new(binder)
+            .add_binding(name)
+            .to_arc_factory(
+                move |_| Arc::new(NamedPlugin(name)),
+                InjectionPoint::for_module_function("command", &[]),
+            );
+    })
+}
+
+struct Commands(HashMap<&'static str, Arc<dyn Plugin>>);
+
+struct CommandsModule;
+
+#[module]
+impl CommandsModule {
+    pub fn provide_commands(commands: HashMap<&'static str, Arc<dyn Plugin>>) -> Commands {
+        Commands(commands)
+    }
+}
+
+#[test]
+fn modules_contribute_to_map_binding() {
+    let injector = Injector::builder()
+        .module(CommandsModule)
+        .module(command_module("build"))
+        .module(command_module("test"))
+        .build()
+        .unwrap();
+
+    let commands = injector.resolve_type::<Commands>().unwrap();
+    assert_eq!(2, commands.0.len());
+    assert_eq!("test", commands.0["test"].name());
+}
+
+#[test]
+fn map_binding_rejects_duplicate_keys() {
+    let errors = Injector::builder()
+        .module(command_module("build"))
+        .module(command_module("build"))
+        .build()
+        .err()
+        .unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::DuplicateMapKey(key, map_key)] => {
+            assert_eq!(&Key::new::<HashMap<&'static str, Arc<dyn Plugin>>>(), key);
+            assert_eq!("\"build\"", map_key);
+        }
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
+
+#[test]
+fn child_can_not_add_elements_to_parent() {
+    let parent = Injector::from_module(plugin_module("parent")).unwrap();
+    let errors = parent.create_child(plugin_module("child")).err().unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::DuplicateBinding(key, _, _)] => {
+            assert_eq!(&Key::new::<Vec<Arc<dyn Plugin>>>(), key)
+        }
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
+
+#[test]
+fn overriding_module_adds_elements() {
+    let injector = Injector::builder()
+        .module(HostModule)
+        .module(Modules::overriding(plugin_module("base")).with(plugin_module("override")))
+        .build()
+        .unwrap();
+
+    let host = injector.resolve_type::<Host>().unwrap();
+    let names: Vec<&str> = host.plugins.iter().map(|plugin| plugin.name()).collect();
+    assert_eq!(vec!["base", "override"], names);
+}
+
+#[test]
+fn element_depending_on_multibinding_is_cyclic() {
+    let errors = Injector::from_module(AnonymousModule::new(|binder| {
+        Multibinder::<dyn Plugin>::new(binder)
+            .add_binding()
+            .to_arc_factory(
+                |injector| {
+                    let _plugins: Arc<Vec<Arc<dyn Plugin>>> = injector.resolve_type().unwrap();
+                    Arc::new(NamedPlugin("recursive"))
+                },
+                InjectionPoint::for_module_function(
+                    "plugin",
+                    &[Key::new::<Vec<Arc<dyn Plugin>>>()],
+                ),
+            );
+    }))
+    .err()
+    .unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::CyclicDependency(chain)] => {
+            assert_eq!(3, chain.len());
+            assert!(chain.contains(&Key::new::<Vec<Arc<dyn Plugin>>>()));
+        }
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use dyn_chassis::meta::InjectionPoint;
+use dyn_chassis::{
+    module, AnonymousModule, ChassisError, Injector, Key, Named, PrivateBinder, PrivateModule,
+    TypedKey,
+};
+
+struct Config(&'static str);
+
+struct Pool(&'static str);
+
+struct Users(Arc<Pool>);
+struct Orders(Arc<Pool>);
+
+struct ConfigModule;
+
+#[module]
+impl ConfigModule {
+    pub fn provide_config() -> Config {
+        Config("db")
+    }
+}
+
+/// binds its own pool named "pool" and exposes `T`
+struct StoreModule<T: 'static>(fn(Arc<Pool>) -> T, &'static str);
+
+impl<T: 'static> PrivateModule for StoreModule<T> {
+    fn configure(&self, binder: &mut PrivateBinder) {
+        let name = self.1;
+        binder
+            .bind::<Pool>()
+            .annotated_with(Named("pool"))
+            .to_factory(
+                move |injector| {
+                    let config: Arc<Config> = injector.resolve_type().unwrap();
+                    assert_eq!("db", config.0);
+                    Pool(name)
+                },
+                InjectionPoint::for_module_function("pool", &[Key::new::<Config>()]),
+            );
+        let create = self.0;
+        binder.bind::<T>().to_factory(
+            move |injector| {
+                create(injector.resolve_to(TypedKey::new_with_annotation(Named("pool"))))
+            },
+            InjectionPoint::for_module_function(
+                "store",
+                &[TypedKey::<Pool>::new_with_annotation(Named("pool")).into()],
+            ),
+        );
+        binder.expose::<T>();
+    }
+}
+
+fn injector() -> Injector {
+    Injector::builder()
+        .module(ConfigModule)
+        .module(StoreModule(Users, "users"))
+        .module(StoreModule(Orders, "orders"))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn private_bindings_do_not_clash() {
+    let injector = injector();
+
+    assert_eq!("users", injector.resolve_type::<Users>().unwrap().0 .0);
+    assert_eq!("orders", injector.resolve_type::<Orders>().unwrap().0 .0);
+    assert!(!injector.contains(TypedKey::<Pool>::new_with_annotation(Named("pool"))));
+}
+
+#[test]
+fn private_modules_outlive_installing_injector() {
+    let child = injector()
+        .create_child(AnonymousModule::new(|_| {}))
+        .unwrap();
+
+    assert_eq!("users", child.resolve_type::<Users>().unwrap().0 .0);
+}
+
+struct UnboundModule;
+
+impl PrivateModule for UnboundModule {
+    fn configure(&self, binder: &mut PrivateBinder) {
+        binder.expose::<Pool>().annotated_with(Named("pool"));
+    }
+}
+
+#[test]
+fn exposed_key_has_to_be_bound() {
+    let errors = Injector::from_module(UnboundModule).err().unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [error @ ChassisError::ExposedNotBound(key, exposed)] => {
+            assert_eq!(
+                &Key::from(TypedKey::<Pool>::new_with_annotation(Named("pool"))),
+                key
+            );
+            assert!(exposed.starts_with("Exposed binding at "), "{}", exposed);
+            assert!(
+                error.to_string().contains("was exposed, but not bound"),
+                "{}",
+                error
+            );
+        }
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
+
+struct ShadowingModule;
+
+impl PrivateModule for ShadowingModule {
+    fn configure(&self, binder: &mut PrivateBinder) {
+        binder.bind::<Config>().to_instance(Config("other"));
+    }
+}
+
+#[test]
+fn private_binding_can_not_rebind_public_key() {
+    let errors = Injector::builder()
+        .module(ConfigModule)
+        .module(ShadowingModule)
+        .build()
+        .err()
+        .unwrap();
+
+    let errors: Vec<&ChassisError> = errors.iter().collect();
+    match errors[..] {
+        [ChassisError::DuplicateBinding(key, _, _)] => assert_eq!(&Key::new::<Config>(), key),
+        _ => panic!("unexpected errors {:?}", errors),
+    }
+}
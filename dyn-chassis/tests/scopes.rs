@@ -0,0 +1,127 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use dyn_chassis::meta::InjectionPoint;
+use dyn_chassis::{module, AnonymousModule, Binder, Injector, Scope, Scopes};
+
+struct Counted(usize);
+
+/// binds `Counted` in `scope` and counts how often it is created
+fn counted_module(
+    scope: &'static dyn Scope,
+    calls: Arc<AtomicUsize>,
+) -> AnonymousModule<impl Fn(&mut Binder)> {
+    AnonymousModule::new(move |binder| {
+        let calls = Arc::clone(&calls);
+        binder.bind::<Counted>().in_(scope).to_factory(
+            move |_| Counted(calls.fetch_add(1, Ordering::SeqCst)),
+            InjectionPoint::for_module_function("counted", &[]),
+        );
+    })
+}
+
+fn counted_injector(scope: &'static dyn Scope) -> (Injector, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let injector = Injector::from_module(counted_module(scope, Arc::clone(&calls))).unwrap();
+    (injector, calls)
+}
+
+#[test]
+fn singleton_is_created_once() {
+    let (injector, calls) = counted_injector(Scopes::SINGLETON);
+    assert_eq!(0, calls.load(Ordering::SeqCst));
+
+    let first = injector.resolve_type::<Counted>().unwrap();
+    let second = injector.resolve_type::<Counted>().unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn eager_singleton_is_created_on_build() {
+    let (injector, calls) = counted_injector(Scopes::EAGER_SINGLETON);
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    injector.resolve_type::<Counted>().unwrap();
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn eager_singleton_failure_fails_build() {
+    let errors = Injector::from_module(AnonymousModule::new(|binder| {
+        binder
+            .bind::<Counted>()
+            .in_(Scopes::EAGER_SINGLETON)
+            .to_try_factory(
+                |_| Err("not available"),
+                InjectionPoint::for_module_function("counted", &[]),
+            );
+    }))
+    .err()
+    .unwrap();
+
+    assert_eq!(
+        "Unable to create injector, see the following errors:\n\n\
+         1) Failed to create `scopes::Counted`: not available\n\n\
+         1 error",
+        errors.to_string()
+    );
+}
+
+#[test]
+fn thread_local_is_reused_on_thread() {
+    let (injector, calls) = counted_injector(Scopes::THREAD_LOCAL);
+
+    let first = injector.resolve_type::<Counted>().unwrap();
+    let second = injector.resolve_type::<Counted>().unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+}
+
+#[test]
+fn thread_local_is_dropped_with_injector() {
+    let (injector, _) = counted_injector(Scopes::THREAD_LOCAL);
+
+    let counted = injector.resolve_type::<Counted>().unwrap();
+    assert_eq!(2, Arc::strong_count(&counted));
+
+    drop(injector);
+    assert_eq!(1, Arc::strong_count(&counted));
+}
+
+#[test]
+fn weak_is_reused_while_alive() {
+    let (injector, calls) = counted_injector(Scopes::WEAK);
+
+    let first = injector.resolve_type::<Counted>().unwrap();
+    let second = injector.resolve_type::<Counted>().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(1, calls.load(Ordering::SeqCst));
+
+    drop(first);
+    drop(second);
+    assert_eq!(1, injector.resolve_type::<Counted>().unwrap().0);
+}
+
+struct Config;
+
+struct ConfigModule;
+
+#[module]
+impl ConfigModule {
+    #[singleton]
+    pub fn provide_config() -> Config {
+        Config
+    }
+}
+
+#[test]
+fn module_singleton() {
+    let injector = Injector::from_module(ConfigModule).unwrap();
+
+    let first = injector.resolve_type::<Config>().unwrap();
+    let second = injector.resolve_type::<Config>().unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
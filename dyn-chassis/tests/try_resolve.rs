@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use dyn_chassis::meta::InjectionPoint;
+use dyn_chassis::{
+    module, AnonymousModule, ChassisError, Injector, Key, ResolveError, ResolveInto, TypedKey,
+};
+
+struct Config;
+struct Connection;
+struct Service;
+
+/// resolves its configuration lazily, so it is not checked when the injector is built
+fn lazy_module() -> AnonymousModule<impl Fn(&mut dyn_chassis::Binder)> {
+    AnonymousModule::new(|binder| {
+        binder.bind::<Connection>().to_try_factory(
+            |injector| -> Result<_, ResolveError> {
+                let _config: Arc<Config> = injector.try_resolve_to(TypedKey::new())?;
+                Ok(Connection)
+            },
+            InjectionPoint::for_module_function("connect", &[]),
+        );
+    })
+}
+
+struct ServiceModule;
+
+#[module]
+impl ServiceModule {
+    pub fn provide_service(_connection: Arc<Connection>) -> Service {
+        Service
+    }
+}
+
+fn injector() -> Injector {
+    Injector::builder()
+        .module(lazy_module())
+        .module(ServiceModule)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn try_resolve_reports_chain() {
+    let err = injector().try_resolve_type::<Service>().err().unwrap();
+
+    assert_eq!(&Key::new::<Config>(), err.key());
+    assert_eq!(
+        &[
+            Key::new::<Service>(),
+            Key::new::<Connection>(),
+            Key::new::<Config>()
+        ],
+        err.chain()
+    );
+}
+
+#[test]
+fn try_resolve_unbound() {
+    let err = injector().try_resolve_type::<Config>().err().unwrap();
+
+    assert_eq!(&[Key::new::<Config>()], err.chain());
+}
+
+#[test]
+fn resolve_unbound_is_none() {
+    assert!(injector().resolve_type::<Config>().is_none());
+}
+
+/// implements only the required method like implementations outside of dyn-chassis
+struct ConfigRef(Arc<Config>);
+
+impl ResolveInto for ConfigRef {
+    type Item = Config;
+
+    fn resolve_into(result: Option<Arc<Config>>, key: &Key) -> Self {
+        ConfigRef(Arc::resolve_into(result, key))
+    }
+}
+
+#[test]
+fn try_resolve_into_by_default() {
+    let result = injector().try_resolve_to::<Config, ConfigRef>(TypedKey::new());
+    assert_eq!(&Key::new::<Config>(), result.err().unwrap().key());
+
+    let config = failing_injector()
+        .try_resolve_to::<Config, ConfigRef>(TypedKey::new())
+        .unwrap();
+    assert_eq!(1, Arc::strong_count(&config.0));
+}
+
+#[test]
+#[should_panic(expected = "Failed to resolve")]
+fn resolve_panics() {
+    injector().resolve_type::<Service>();
+}
+
+#[derive(Debug)]
+struct ConnectError;
+
+impl std::fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("connection refused")
+    }
+}
+
+impl std::error::Error for ConnectError {}
+
+struct FailingModule;
+
+#[module]
+impl FailingModule {
+    pub fn provide_config() -> Config {
+        Config
+    }
+
+    pub fn provide_connection(_config: Arc<Config>) -> Result<Arc<Connection>, ConnectError> {
+        Err(ConnectError)
+    }
+}
+
+fn failing_injector() -> Injector {
+    Injector::builder()
+        .module(FailingModule)
+        .module(ServiceModule)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn try_resolve_reports_creation_failure() {
+    let err = failing_injector()
+        .try_resolve_type::<Service>()
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        &[Key::new::<Service>(), Key::new::<Connection>()],
+        err.chain()
+    );
+    match err.cause() {
+        Some(ChassisError::CreationFailed(key, error)) => {
+            assert_eq!(&Key::new::<Connection>(), key);
+            assert!(error.is::<ConnectError>());
+        }
+        other => panic!("unexpected cause {:?}", other),
+    }
+    assert_eq!(
+        "Failed to create `try_resolve::Connection`: connection refused\n  \
+         required by `try_resolve::Service`",
+        err.to_string()
+    );
+}
+
+struct AliasModule;
+
+#[module]
+impl AliasModule {
+    pub fn provide_connection() -> std::io::Result<Arc<Connection>> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        ))
+    }
+}
+
+#[test]
+fn try_resolve_result_alias() {
+    let injector = Injector::builder()
+        .module(AliasModule)
+        .module(ServiceModule)
+        .build()
+        .unwrap();
+    let err = injector.try_resolve_type::<Service>().err().unwrap();
+
+    match err.cause() {
+        Some(ChassisError::CreationFailed(key, error)) => {
+            assert_eq!(&Key::new::<Connection>(), key);
+            assert!(error.is::<std::io::Error>());
+        }
+        other => panic!("unexpected cause {:?}", other),
+    }
+}
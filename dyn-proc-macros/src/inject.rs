@@ -4,7 +4,7 @@ use proc_macro2::{Ident, Span};
 use syn::parse::{Parse, ParseStream};
 use syn::Expr;
 
-use crate::attributes::get_annotation_attribute;
+use crate::attributes::{get_annotation_attribute, has_singleton_attribute};
 use crate::signature::{process_sig, InjectFn, WrapperType};
 use crate::syn_ext::IdentExt;
 use proc_macro2::TokenStream as TokenStream2;
@@ -78,13 +78,13 @@ pub fn codegen_injectfns(
     }
 
     let resolves = dep_keys.iter().map(|key| {
-        quote! { __sl__.resolve_to(#key) }
+        quote! { __sl__.try_resolve_to(#key)? }
     });
 
     let factory = match &sig.output.wrapper {
-        Some(WrapperType::Arc) => "to_arc_factory",
-        Some(WrapperType::Box) => "to_box_factory",
-        None => "to_factory",
+        Some(WrapperType::Arc) => "to_try_arc_factory",
+        Some(WrapperType::Box) => "to_try_box_factory",
+        None => "to_try_factory",
     };
     let factory_ident = Ident::new(factory, Span::call_site());
     let rty_token = if return_self {
@@ -99,6 +99,11 @@ pub fn codegen_injectfns(
     } else {
         TokenStream2::new()
     };
+    let scope = if has_singleton_attribute(&sig.attrs) {
+        quote! { binding.in_singleton(); }
+    } else {
+        TokenStream2::new()
+    };
 
     let code_metafn = quote_spanned! {span=>
         pub fn #metafn_name(__binder__: &mut dyn_chassis::Binder) {
@@ -106,6 +111,7 @@ pub fn codegen_injectfns(
 
             let mut binding = __binder__.bind::<#rty_token>();
             #annotation
+            #scope
             binding.#factory_ident(
                     Self::#injectfn_name,
                     dyn_chassis::meta::InjectionPoint::for_module_function(
@@ -116,17 +122,32 @@ pub fn codegen_injectfns(
         }
     };
 
+    // errors of fallible functions are boxed, failures to resolve dependencies are downcasted
+    // back to `ResolveError` by the binding
+    let (error_ty, call) = if sig.fallible {
+        (
+            quote! { ::std::boxed::Box<dyn ::std::error::Error> },
+            quote! { Self::#userfn_name(#(#resolves),*).map_err(::std::convert::Into::into) },
+        )
+    } else {
+        (
+            quote! { dyn_chassis::ResolveError },
+            quote! { ::std::result::Result::Ok(Self::#userfn_name(#(#resolves),*)) },
+        )
+    };
+
     let code_injectfn = if return_self {
         quote! {
-            pub fn #injectfn_name(__sl__: &dyn_chassis::Injector) -> Self {
-                Self::#userfn_name(#(#resolves),*)
+            pub fn #injectfn_name(
+                __sl__: &dyn_chassis::Injector
+            ) -> ::std::result::Result<Self, #error_ty> {
+                #call
             }
         }
     } else {
         let rty = &sig.output.outer_ty;
-        let body = quote! { Self::#userfn_name(#(#resolves),*) };
         let fn_sig = quote! { pub fn #injectfn_name(__sl__: &dyn_chassis::Injector) };
-        quote! { #fn_sig -> #rty { #body } }
+        quote! { #fn_sig -> ::std::result::Result<#rty, #error_ty> { #call } }
     };
 
     quote! {
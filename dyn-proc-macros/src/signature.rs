@@ -12,6 +12,8 @@ pub struct InjectFn {
     pub name: Ident,
     pub inputs: Vec<InjectFnArg>,
     pub output: InjectType,
+    /// function returns a `Result`, its error is boxed with `Into<Box<dyn Error>>`
+    pub fallible: bool,
     pub attrs: Vec<InjectAttr>,
 }
 
@@ -63,16 +65,20 @@ pub fn process_sig(function: &mut syn::ImplItemMethod) -> InjectFn {
 
     let chassis_attrs: Vec<Attribute> = drain_where(&mut function.attrs, is_chassis_attr);
 
-    let rty: InjectType = match &function.sig.output {
+    let (rty, fallible): (InjectType, bool) = match &function.sig.output {
         syn::ReturnType::Default => panic!("return type required"),
         // TODO: check for type: no lifetime, ...
-        syn::ReturnType::Type(_, ty) => parse_inject_type(ty),
+        syn::ReturnType::Type(_, ty) => match find_result_type(ty) {
+            Some(ok_ty) => (parse_inject_type(&ok_ty), true),
+            None => (parse_inject_type(ty), false),
+        },
     };
 
     InjectFn {
         name: function.sig.ident.clone(),
         inputs,
         output: rty,
+        fallible,
         attrs: chassis_attrs.into_iter().map(parse_attr).collect(),
     }
 }
@@ -94,6 +100,31 @@ fn extract_single_generic_arg(path_seg: &PathSegment) -> Type {
     }
 }
 
+// Find `T` of `Result<T, E>` or of an alias with one argument like `io::Result<T>`
+fn find_result_type(ty: &Type) -> Option<Type> {
+    if let Type::Path(path) = ty {
+        let seg = path.path.segments.last()?;
+        if seg.ident != "Result" {
+            return None;
+        }
+
+        if let PathArguments::AngleBracketed(args) = &seg.arguments {
+            let types: Vec<&Type> = args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect();
+            if let [ok_ty] | [ok_ty, _] = types[..] {
+                return Some(ok_ty.clone());
+            }
+        }
+    }
+    None
+}
+
 fn find_wrapper_type(ty: &Type) -> Option<InjectType> {
     if let Type::Path(path) = ty {
         let segments = &path.path.segments;